@@ -13,28 +13,152 @@ impl InternalFTData {
 #[near_bindgen]
 impl Keypom {
     /// Standard function for accepting FTs to then be claimable as part of linkdrops.
+    /// If the message is prefixed with `referral&`, the FTs will fund the drop's referral rewards instead.
+    /// If the drop has an FT deposit config, FTs from senders that aren't allowed are returned, as are any FTs
+    /// Beyond what the remaining uses need if `return_excess` is set. Deposits from anyone other than the funder are then
+    /// Tracked so that they can be reclaimed. The sender's balance pays for the storage used to track their deposit
+    /// And all their FTs are returned if it can't cover it.
+    /// For drops crowdfunding this FT, tokens from anyone other than the funder are only accepted while the
+    /// Crowdfund is open.
     pub fn ft_on_transfer(
         &mut self,
+        sender_id: AccountId,
         amount: U128,
         msg: DropId,
     ) -> PromiseOrValue<U128> {
         self.assert_no_global_freeze();
-        
-        let drop_id = msg;
-        let asset_id = env::predecessor_account_id();
+        let initial_storage = env::storage_usage();
+
+        let ft_contract_id = env::predecessor_account_id();
+        let (drop_id, asset_id) = match msg.strip_prefix(REFERRAL_ASSET_PREFIX) {
+            Some(drop_id) => (
+                drop_id.to_string(),
+                format!("{}{}", REFERRAL_ASSET_PREFIX, ft_contract_id),
+            ),
+            None => (msg.clone(), ft_contract_id.to_string()),
+        };
         let mut drop: InternalDrop = self.drop_by_id.get(&drop_id).expect("Drop not found");
 
-        let mut asset = drop.asset_by_id.get(&asset_id.to_string()).expect("Asset not found");
-        // Ensure asset is fungible token and then call the internal function
-        if let InternalAsset::ft(ft_data) = &mut asset {
-            ft_data.add_to_balance_avail(&amount.0);
-            near_sdk::log!("Added {} FTs to drop ID {}. New asset amount: {}", amount.0, drop_id, ft_data.balance_avail);
+        let mut asset = drop.asset_by_id.get(&asset_id).expect("Asset not found");
+        let ft_data = match &mut asset {
+            InternalAsset::ft(ft_data) => ft_data,
+            _ => env::panic_str("Asset is not a fungible token"),
         };
 
-        drop.asset_by_id.insert(&asset_id.to_string(), &asset);
+        let mut tokens_to_accept = amount.0;
+        let deposit_config = drop
+            .config
+            .as_ref()
+            .and_then(|c| c.ft_deposit_config.clone());
+        if let Some(deposit_config) = &deposit_config {
+            let is_allowed = sender_id == drop.funder_id
+                || deposit_config
+                    .allowed_senders
+                    .as_ref()
+                    .map(|senders| senders.contains(&sender_id))
+                    .unwrap_or(false);
+            if !is_allowed {
+                near_sdk::log!(
+                    "{} is not allowed to send FTs to drop ID {}. Returning {} FTs",
+                    sender_id,
+                    drop_id,
+                    amount.0
+                );
+                return PromiseOrValue::Value(amount);
+            }
+
+            if deposit_config.return_excess.unwrap_or(false) {
+                let required = drop
+                    .tokens_required_by_asset
+                    .get(&asset_id)
+                    .copied()
+                    .unwrap_or(0);
+                tokens_to_accept =
+                    tokens_to_accept.min(required.saturating_sub(ft_data.balance_avail));
+            }
+        }
+
+        // Only FTs sent while a crowdfund is open count towards its target
+        let crowdfund_status = drop
+            .config
+            .as_ref()
+            .and_then(|c| c.crowdfund.as_ref())
+            .filter(|c| {
+                c.ft_contract_id.as_ref() == Some(&ft_contract_id) && asset_id == ft_contract_id
+            })
+            .and(drop.get_crowdfund_status());
+        let is_contribution = crowdfund_status == Some(CrowdfundStatus::open);
+        if crowdfund_status.is_some() && !is_contribution && sender_id != drop.funder_id {
+            near_sdk::log!(
+                "Crowdfund for drop ID {} is no longer open. Returning {} FTs",
+                drop_id,
+                amount.0
+            );
+            return PromiseOrValue::Value(amount);
+        }
+
+        let mut event_logs = Vec::new();
 
-        self.drop_by_id.insert(&drop_id, &drop);
+        if tokens_to_accept > 0 {
+            ft_data.add_to_balance_avail(&tokens_to_accept);
+            near_sdk::log!(
+                "Added {} FTs to drop ID {}. New asset amount: {}",
+                tokens_to_accept,
+                drop_id,
+                ft_data.balance_avail
+            );
+            drop.asset_by_id.insert(&asset_id, &asset);
 
-        PromiseOrValue::Value(U128(0))
+            // Keep track of what contributors other than the funder sent so they can reclaim it
+            let is_tracked =
+                (deposit_config.is_some() || is_contribution) && sender_id != drop.funder_id;
+            if is_tracked {
+                let deposit_key = (sender_id.clone(), asset_id.clone());
+                let deposited = drop.ft_deposits_by_sender.get(&deposit_key).unwrap_or(0);
+                drop.ft_deposits_by_sender
+                    .insert(&deposit_key, &(deposited + tokens_to_accept));
+                *drop
+                    .ft_contributed_by_asset
+                    .entry(asset_id.clone())
+                    .or_insert(0) += tokens_to_accept;
+            }
+
+            if is_contribution {
+                drop.crowdfund_raised += tokens_to_accept;
+                event_logs.push(EventLog {
+                    standard: KEYPOM_STANDARD_NAME.to_string(),
+                    version: KEYPOM_STANDARD_VERSION.to_string(),
+                    event: EventLogVariant::CrowdfundContribution(CrowdfundContributionLog {
+                        drop_id: drop_id.to_string(),
+                        contributor_id: sender_id.to_string(),
+                        ft_contract_id: Some(ft_contract_id.to_string()),
+                        amount: tokens_to_accept.to_string(),
+                        total_raised: drop.crowdfund_raised.to_string(),
+                    }),
+                });
+            }
+
+            self.drop_by_id.insert(&drop_id, &drop);
+
+            // The predecessor is the FT contract so the storage is charged to a balance directly
+            let final_storage = env::storage_usage();
+            if final_storage > initial_storage {
+                let storage_cost = (final_storage - initial_storage) as u128
+                    * env::storage_byte_cost().as_yoctonear();
+                let payer_id = if is_tracked {
+                    &sender_id
+                } else {
+                    &drop.funder_id
+                };
+                self.internal_modify_user_balance(payer_id, storage_cost, true);
+            }
+        }
+        log_events(event_logs);
+
+        let tokens_to_return = amount.0 - tokens_to_accept;
+        if tokens_to_return > 0 {
+            near_sdk::log!("Returning {} excess FTs to {}", tokens_to_return, sender_id);
+        }
+        PromiseOrValue::Value(U128(tokens_to_return))
     }
-}
\ No newline at end of file
+}
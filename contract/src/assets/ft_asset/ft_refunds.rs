@@ -15,43 +15,154 @@ trait ExtFTContract {
 #[near_bindgen]
 impl Keypom {
     /// Allows users to attach fungible tokens to the Linkdrops. Must have storage recorded by this point. You can only attach one set of FTs or NFT at a time.
+    /// If `referral_asset` is true, the tokens are withdrawn from the drop's referral rewards instead of the claim assets.
     pub fn withdraw_ft_balance(
         &mut self,
         drop_id: DropId,
         ft_contract_id: AccountId,
         tokens_to_withdraw: U128,
+        referral_asset: Option<bool>,
     ) {
         self.assert_no_global_freeze();
 
         // get the drop object
         let mut drop = self.drop_by_id.get(&drop_id).expect("No drop found");
-        let funder_id = &drop.funder_id;
-
-        require!(
-            funder_id == &env::predecessor_account_id(),
-            "Only drop funder can delete keys"
+        assert_drop_permission(
+            &drop,
+            &env::predecessor_account_id(),
+            DropOperation::withdraw_assets,
         );
 
-        let mut asset: InternalAsset = drop
-            .asset_by_id
-            .get(&ft_contract_id.to_string())
-            .expect("Asset not found");
+        let asset_id = if referral_asset.unwrap_or(false) {
+            format!("{}{}", REFERRAL_ASSET_PREFIX, ft_contract_id)
+        } else {
+            ft_contract_id.to_string()
+        };
+        let mut asset: InternalAsset = drop.asset_by_id.get(&asset_id).expect("Asset not found");
+        // Tokens that contributors deposited can't be withdrawn by the funder
+        let contributed = drop
+            .ft_contributed_by_asset
+            .get(&asset_id)
+            .copied()
+            .unwrap_or(0);
         // Ensure asset is fungible token and then call the internal function
         if let InternalAsset::ft(ft_data) = &mut asset {
-            ft_data.ft_refund(&drop_id, tokens_to_withdraw.into(), &drop.funder_id);
+            require!(
+                ft_data.balance_avail.saturating_sub(contributed) >= tokens_to_withdraw.0,
+                format!(
+                    "Cannot withdraw {} FTs. {} of the {} available were deposited by contributors",
+                    tokens_to_withdraw.0, contributed, ft_data.balance_avail
+                )
+            );
+            ft_data.ft_refund(
+                &drop_id,
+                &asset_id,
+                tokens_to_withdraw.into(),
+                &drop.funder_id,
+                None,
+            );
         };
 
-        drop.asset_by_id.insert(&ft_contract_id.to_string(), &asset);
+        drop.asset_by_id.insert(&asset_id, &asset);
 
         self.drop_by_id.insert(&drop_id, &drop);
     }
 
+    /// Allows a contributor other than the funder to reclaim FTs they sent to a drop. As many of their tokens
+    /// As the drop holds beyond what its remaining uses need are returned and whatever is left of their deposit
+    /// Can be reclaimed later. Any storage released is credited back to the contributor's balance.
+    /// If `referral_asset` is true, the tokens are reclaimed from the drop's referral rewards instead of the claim assets.
+    /// Contributions to a crowdfund can only be reclaimed if the crowdfund failed.
+    pub fn reclaim_ft_deposit(
+        &mut self,
+        drop_id: DropId,
+        ft_contract_id: AccountId,
+        referral_asset: Option<bool>,
+    ) -> U128 {
+        self.assert_no_global_freeze();
+        let initial_storage = env::storage_usage();
+        let sender_id = env::predecessor_account_id();
+
+        let mut drop = self.drop_by_id.get(&drop_id).expect("No drop found");
+        let asset_id = if referral_asset.unwrap_or(false) {
+            format!("{}{}", REFERRAL_ASSET_PREFIX, ft_contract_id)
+        } else {
+            ft_contract_id.to_string()
+        };
+        let deposit_key = (sender_id.clone(), asset_id.clone());
+        let deposited = drop
+            .ft_deposits_by_sender
+            .get(&deposit_key)
+            .expect("No deposit found for sender");
+
+        // Crowdfund contributions are locked in unless the crowdfund fails
+        let is_crowdfunded_asset = drop
+            .config
+            .as_ref()
+            .and_then(|c| c.crowdfund.as_ref())
+            .and_then(|c| c.ft_contract_id.as_ref())
+            .map(|id| *id == asset_id)
+            .unwrap_or(false);
+        if is_crowdfunded_asset {
+            require!(
+                drop.get_crowdfund_status() == Some(CrowdfundStatus::failed),
+                "Crowdfund contributions can only be reclaimed once the crowdfund has failed"
+            );
+        }
+
+        let mut asset: InternalAsset = drop.asset_by_id.get(&asset_id).expect("Asset not found");
+        let ft_data = match &mut asset {
+            InternalAsset::ft(ft_data) => ft_data,
+            _ => env::panic_str("Asset is not a fungible token"),
+        };
+        // Tokens still needed by the remaining uses can't be reclaimed. Keys in a failed crowdfund can't be claimed
+        // So everything the drop holds is up for grabs
+        let reserved = if is_crowdfunded_asset {
+            0
+        } else {
+            drop.tokens_required_by_asset
+                .get(&asset_id)
+                .copied()
+                .unwrap_or(0)
+        };
+        let tokens_to_reclaim = deposited.min(ft_data.balance_avail.saturating_sub(reserved));
+        require!(tokens_to_reclaim > 0, "No tokens left to reclaim");
+
+        if tokens_to_reclaim == deposited {
+            drop.ft_deposits_by_sender.remove(&deposit_key);
+        } else {
+            drop.ft_deposits_by_sender
+                .insert(&deposit_key, &(deposited - tokens_to_reclaim));
+        }
+        drop.remove_ft_contribution(&asset_id, tokens_to_reclaim);
+        ft_data.ft_refund(
+            &drop_id,
+            &asset_id,
+            tokens_to_reclaim,
+            &sender_id,
+            Some(sender_id.clone()),
+        );
+        drop.asset_by_id.insert(&asset_id, &asset);
+        self.drop_by_id.insert(&drop_id, &drop);
+
+        // The contributor paid for the deposit's storage so they get it back
+        let final_storage = env::storage_usage();
+        if initial_storage > final_storage {
+            let storage_refund =
+                (initial_storage - final_storage) as u128 * env::storage_byte_cost().as_yoctonear();
+            self.internal_modify_user_balance(&sender_id, storage_refund, false);
+        }
+
+        U128(tokens_to_reclaim)
+    }
+
     #[private]
     pub fn ft_resolve_refund(
         &mut self,
         drop_id: DropId,
         asset_id: AssetId,
         tokens_to_transfer: Balance,
+        contributor_id: Option<AccountId>,
     ) -> bool {
         let transfer_succeeded = matches!(env::promise_result(0), PromiseResult::Successful(_));
 
@@ -85,6 +196,18 @@ impl Keypom {
             panic!("asset is not FT");
         }
 
+        // A contributor's deposit should be reclaimable again
+        if let Some(contributor_id) = contributor_id {
+            let deposit_key = (contributor_id, asset_id);
+            let deposited = drop.ft_deposits_by_sender.get(&deposit_key).unwrap_or(0);
+            drop.ft_deposits_by_sender
+                .insert(&deposit_key, &(deposited + tokens_to_transfer));
+            *drop
+                .ft_contributed_by_asset
+                .entry(deposit_key.1)
+                .or_insert(0) += tokens_to_transfer;
+        }
+
         self.drop_by_id.insert(&drop_id, &drop);
 
         false
@@ -94,11 +217,14 @@ impl Keypom {
 impl InternalFTData {
     /// Automatically refund a claim for fungible tokens
     /// This should refund the FTs & any storage deposits.
+    /// If the tokens are being reclaimed by a contributor, their deposit is restored should the transfer fail.
     pub fn ft_refund(
         &mut self,
         drop_id: &DropId,
+        asset_id: &AssetId,
         tokens_to_transfer: Balance,
         refund_to: &AccountId,
+        contributor_id: Option<AccountId>,
     ) {
         require!(
             self.enough_balance(&tokens_to_transfer),
@@ -118,7 +244,7 @@ impl InternalFTData {
         // All FTs can be refunded at once. Funder responsible for registering themselves
         ext_ft_contract::ext(self.contract_id.clone())
             // Call ft transfer with 1 yoctoNEAR. 1/2 unspent GAS will be added on top
-            .with_attached_deposit(NearToken::from_near(1))
+            .with_attached_deposit(NearToken::from_yoctonear(1))
             .with_static_gas(MIN_GAS_FOR_FT_TRANSFER)
             .ft_transfer(
                 refund_to.clone(),
@@ -132,8 +258,9 @@ impl InternalFTData {
                     .with_static_gas(MIN_GAS_FOR_RESOLVE_REFUND)
                     .ft_resolve_refund(
                         drop_id.to_string(),
-                        self.contract_id.to_string(),
+                        asset_id.to_string(),
                         tokens_to_transfer,
+                        contributor_id,
                     ),
             )
             .as_return();
@@ -21,11 +21,10 @@ impl Keypom {
 
         // get the drop object
         let mut drop = self.drop_by_id.get(&drop_id).expect("No drop found");
-        let funder_id = &drop.funder_id;
-
-        require!(
-            funder_id == &env::predecessor_account_id(),
-            "Only drop funder can delete keys"
+        assert_drop_permission(
+            &drop,
+            &env::predecessor_account_id(),
+            DropOperation::withdraw_assets,
         );
 
         let mut asset: InternalAsset = drop
@@ -0,0 +1,153 @@
+use crate::*;
+
+#[near_bindgen]
+impl Keypom {
+    /// Contribute $NEAR towards a crowdfunded drop. The storage used to record the contribution is taken out of the
+    /// Attached deposit and the rest counts towards the target. If the crowdfund fails, the contribution can be
+    /// Reclaimed using `reclaim_near_contribution`. Returns the total raised by the drop.
+    ///
+    /// Requirements:
+    /// * The drop must be raising $NEAR and its crowdfund must still be open.
+    #[payable]
+    pub fn contribute_to_drop(&mut self, drop_id: DropId) -> U128 {
+        self.assert_no_global_freeze();
+        let initial_storage = env::storage_usage();
+        let contributor_id = env::predecessor_account_id();
+        let attached_deposit = env::attached_deposit().as_yoctonear();
+
+        let mut drop = self.drop_by_id.get(&drop_id).expect("No drop found");
+        let crowdfund = drop
+            .config
+            .as_ref()
+            .and_then(|c| c.crowdfund.as_ref())
+            .expect("Drop is not crowdfunded");
+        require!(
+            crowdfund.ft_contract_id.is_none(),
+            "Drop is crowdfunding FTs. Send them using ft_transfer_call"
+        );
+        require!(
+            drop.get_crowdfund_status() == Some(CrowdfundStatus::open),
+            "Crowdfund is no longer open"
+        );
+
+        // Record the contribution first so the storage it uses is known
+        let contributed = drop
+            .near_contributions_by_sender
+            .get(&contributor_id)
+            .unwrap_or(0);
+        drop.near_contributions_by_sender
+            .insert(&contributor_id, &contributed);
+        let storage_cost = (env::storage_usage() - initial_storage) as u128
+            * env::storage_byte_cost().as_yoctonear();
+        require!(
+            attached_deposit > storage_cost,
+            format!(
+                "Must attach more than {} yoctoNEAR to cover storage",
+                storage_cost
+            )
+        );
+
+        let amount = attached_deposit - storage_cost;
+        drop.near_contributions_by_sender
+            .insert(&contributor_id, &(contributed + amount));
+        drop.crowdfund_raised += amount;
+        let total_raised = drop.crowdfund_raised;
+        self.drop_by_id.insert(&drop_id, &drop);
+
+        log_events(vec![EventLog {
+            standard: KEYPOM_STANDARD_NAME.to_string(),
+            version: KEYPOM_STANDARD_VERSION.to_string(),
+            event: EventLogVariant::CrowdfundContribution(CrowdfundContributionLog {
+                drop_id: drop_id.to_string(),
+                contributor_id: contributor_id.to_string(),
+                ft_contract_id: None,
+                amount: amount.to_string(),
+                total_raised: total_raised.to_string(),
+            }),
+        }]);
+        U128(total_raised)
+    }
+
+    /// Reclaim the $NEAR that the predecessor contributed to a crowdfunded drop, along with the storage
+    /// That was used to record it. Returns how much was refunded.
+    ///
+    /// Requirements:
+    /// * The crowdfund must have failed.
+    pub fn reclaim_near_contribution(&mut self, drop_id: DropId) -> U128 {
+        self.assert_no_global_freeze();
+        let initial_storage = env::storage_usage();
+        let contributor_id = env::predecessor_account_id();
+
+        let mut drop = self.drop_by_id.get(&drop_id).expect("No drop found");
+        require!(
+            drop.get_crowdfund_status() == Some(CrowdfundStatus::failed),
+            "Crowdfund contributions can only be reclaimed once the crowdfund has failed"
+        );
+
+        // Remove the contribution before transferring to prevent re-entrancy
+        let contributed = drop
+            .near_contributions_by_sender
+            .remove(&contributor_id)
+            .expect("No contribution found for account");
+        self.drop_by_id.insert(&drop_id, &drop);
+
+        let storage_refund = (initial_storage - env::storage_usage()) as u128
+            * env::storage_byte_cost().as_yoctonear();
+        let refund_amount = contributed + storage_refund;
+        near_sdk::log!(
+            "Refunding {} contributed and {} storage to {}",
+            contributed,
+            storage_refund,
+            contributor_id
+        );
+        Promise::new(contributor_id).transfer(NearToken::from_yoctonear(refund_amount));
+
+        U128(refund_amount)
+    }
+
+    /// Once a crowdfunded drop has met its target, hand everything that was raised over to the funder.
+    /// Any $NEAR contributed is added to the funder's balance so it can pay for keys and the FTs contributed
+    /// Can be withdrawn by the funder. Contributions can't be reclaimed once the target is met so the records
+    /// Of who contributed are left in place. Returns how much $NEAR was collected.
+    ///
+    /// Requirements:
+    /// * Only the funder can collect the crowdfund.
+    /// * The crowdfund must have met its target and can only be collected once.
+    pub fn collect_crowdfund(&mut self, drop_id: DropId) -> U128 {
+        self.assert_no_global_freeze();
+
+        let mut drop = self.drop_by_id.get(&drop_id).expect("No drop found");
+        require!(
+            drop.funder_id == env::predecessor_account_id(),
+            "Only drop funder can collect the crowdfund"
+        );
+        require!(
+            drop.get_crowdfund_status() == Some(CrowdfundStatus::funded),
+            "Crowdfund has not met its target"
+        );
+        require!(
+            !drop.crowdfund_collected,
+            "Crowdfund has already been collected"
+        );
+        drop.crowdfund_collected = true;
+
+        // FT contributions now belong to the drop while $NEAR goes to the funder's balance
+        let near_collected = match drop
+            .config
+            .as_ref()
+            .and_then(|c| c.crowdfund.as_ref())
+            .and_then(|c| c.ft_contract_id.clone())
+        {
+            Some(ft_contract_id) => {
+                drop.ft_contributed_by_asset
+                    .remove(&ft_contract_id.to_string());
+                0
+            }
+            None => drop.crowdfund_raised,
+        };
+        self.drop_by_id.insert(&drop_id, &drop);
+        self.internal_modify_user_balance(&drop.funder_id, near_collected, false);
+
+        U128(near_collected)
+    }
+}
@@ -11,20 +11,35 @@ impl Keypom {
         receiver_id: AccountId,
         fc_args: Option<UserProvidedFCArgs>,
         new_public_key: PublicKey,
+        referrer_id: Option<AccountId>,
+        pay_it_forward_reservation: Option<PayItForwardReservation>,
     ) -> PromiseOrValue<bool> {
         let successful_creation = was_account_created();
 
-        // If the account was successfully created, we should claim the assets
+        // If the account was successfully created, we should mint any pay it forward keys and claim the assets
         // Otherwise, we should loop through all the assets in the current use and refund the tokens
         if successful_creation {
+            if let Some(reservation) = pay_it_forward_reservation {
+                let mut event_logs = Vec::new();
+                self.internal_mint_pay_it_forward_keys(&mut event_logs, &receiver_id, reservation);
+                log_events(event_logs);
+            }
+
+            // Gated uses are rejected before the account is created so the assets can be claimed directly
             return self.internal_claim_assets(
                 token_id,
                 receiver_id,
                 fc_args,
                 Some(new_public_key),
+                referrer_id,
             );
         }
 
+        // The pay it forward keys were never minted so they go back into the budget
+        if let Some(reservation) = pay_it_forward_reservation {
+            self.internal_release_pay_it_forward_keys(reservation);
+        }
+
         let initial_storage = env::storage_usage();
         let (drop_id, _) = parse_token_id(&token_id).unwrap();
         let mut drop: InternalDrop = self.drop_by_id.get(&drop_id).expect("Drop not found");
@@ -36,7 +51,7 @@ impl Keypom {
         let cur_key_use = get_key_cur_use(&drop, &key_info) - 1;
         let InternalAssetDataForUses {
             uses: _,
-            config: _,
+            config: use_config,
             assets_metadata,
             required_asset_gas: _,
         } = get_asset_data_for_specific_use(&drop.asset_data_for_uses, &cur_key_use);
@@ -50,6 +65,11 @@ impl Keypom {
             self.internal_modify_user_balance(&drop.funder_id, amount_to_increment, false);
         }
 
+        // No referral reward is paid out since nothing was claimed
+        if let Some(referral) = use_config.as_ref().and_then(|c| c.referral.as_ref()) {
+            self.internal_handle_referral(&mut drop, &drop_id, referral, None, false);
+        }
+
         // Loop through all the assets in the drop and ensure they're empty
         let mut is_drop_empty = true;
         for asset in drop.asset_by_id.values() {
@@ -78,6 +98,7 @@ impl Keypom {
         &mut self,
         token_id: TokenId,
         token_ids_transferred: Vec<Option<TokenId>>,
+        referrer_id: Option<AccountId>,
     ) -> PromiseOrValue<bool> {
         let num_promises = env::promise_results_count();
 
@@ -92,7 +113,7 @@ impl Keypom {
         let cur_key_use = get_key_cur_use(&drop, &key_info) - 1;
         let InternalAssetDataForUses {
             uses: _,
-            config: _,
+            config: use_config,
             assets_metadata,
             required_asset_gas: _,
         } = get_asset_data_for_specific_use(&drop.asset_data_for_uses, &cur_key_use);
@@ -150,6 +171,19 @@ impl Keypom {
             }
         }
 
+        // Pay out the referral reward now that we know whether or not the claim was successful.
+        // Storage for a new referrer's counter is covered by the reward's reserve so it shouldn't count against the key cleanup
+        let mut initial_storage = initial_storage;
+        if let Some(referral) = use_config.as_ref().and_then(|c| c.referral.as_ref()) {
+            initial_storage += self.internal_handle_referral(
+                &mut drop,
+                &drop_id,
+                referral,
+                referrer_id,
+                was_successful,
+            );
+        }
+
         // Potential error -> because we only re-insert the drop at the end of the loop, what happens
         // If the promise result is not ready? Do we lose all the modifications that we made to the drop?
         self.drop_by_id.insert(&drop_id, &drop);
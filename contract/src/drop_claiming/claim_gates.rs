@@ -0,0 +1,327 @@
+use near_sdk::PromiseResult;
+
+use crate::*;
+
+#[near_bindgen]
+impl Keypom {
+    /// Claim the assets for the key's current use. If the use is gated, the eligibility check is fired first
+    /// And the assets are only claimed in `on_claim_gate_checked` once the check passes.
+    /// Whatever the claim set aside in *pending_claim* is used right before the assets are claimed.
+    pub(crate) fn internal_gated_claim_assets(
+        &mut self,
+        token_id: TokenId,
+        receiver_id: AccountId,
+        fc_args: Option<UserProvidedFCArgs>,
+        new_public_key: Option<PublicKey>,
+        referrer_id: Option<AccountId>,
+        pending_claim: PendingClaim,
+    ) -> PromiseOrValue<bool> {
+        let (drop_id, _) = parse_token_id(&token_id).unwrap();
+        let drop: InternalDrop = self.drop_by_id.get(&drop_id).expect("Drop not found");
+        let key_info = drop
+            .key_info_by_token_id
+            .get(&token_id)
+            .expect("Key not found");
+
+        // The uses were decremented before the claim, so we need to increment them back to get the current use
+        let cur_key_use = get_key_cur_use(&drop, &key_info) - 1;
+        let claim_gate = get_asset_data_for_specific_use(&drop.asset_data_for_uses, &cur_key_use)
+            .config
+            .and_then(|c| c.claim_gate);
+
+        if let Some(gate) = claim_gate {
+            let (contract_id, method_name, args) = match gate {
+                ClaimGate::nft_holder { contract_id } => (
+                    contract_id,
+                    "nft_supply_for_owner".to_string(),
+                    json!({ "account_id": receiver_id }),
+                ),
+                ClaimGate::ft_balance { contract_id, .. } => (
+                    contract_id,
+                    "ft_balance_of".to_string(),
+                    json!({ "account_id": receiver_id }),
+                ),
+                ClaimGate::custom {
+                    contract_id,
+                    method_name,
+                } => (
+                    contract_id,
+                    method_name.unwrap_or("is_eligible".to_string()),
+                    json!({ "account_id": receiver_id }),
+                ),
+            };
+
+            return PromiseOrValue::Promise(
+                Promise::new(contract_id)
+                    .function_call_weight(
+                        method_name,
+                        args.to_string().into(),
+                        NearToken::from_yoctonear(0),
+                        GAS_FOR_CLAIM_GATE_CHECK,
+                        GasWeight(0),
+                    )
+                    .then(
+                        Self::ext(env::current_account_id())
+                            .with_static_gas(MIN_GAS_FOR_RECEIPT_COMPUTATION)
+                            .with_unused_gas_weight(1)
+                            .on_claim_gate_checked(
+                                token_id,
+                                receiver_id,
+                                fc_args,
+                                new_public_key,
+                                referrer_id,
+                                pending_claim,
+                            ),
+                    ),
+            );
+        }
+
+        self.internal_claim_pending_assets(
+            token_id,
+            receiver_id,
+            fc_args,
+            new_public_key,
+            referrer_id,
+            pending_claim,
+        )
+    }
+
+    /// Resolve the eligibility check for a gated use. If the check passed, the assets are claimed.
+    /// Otherwise, the use is given back to the key, its last claimed time is restored and whatever
+    /// The claim set aside is given back.
+    #[private]
+    pub fn on_claim_gate_checked(
+        &mut self,
+        token_id: TokenId,
+        receiver_id: AccountId,
+        fc_args: Option<UserProvidedFCArgs>,
+        new_public_key: Option<PublicKey>,
+        referrer_id: Option<AccountId>,
+        pending_claim: PendingClaim,
+    ) -> PromiseOrValue<bool> {
+        let (drop_id, _) = parse_token_id(&token_id).unwrap();
+        let mut drop: InternalDrop = self.drop_by_id.get(&drop_id).expect("Drop not found");
+        let mut key_info = drop
+            .key_info_by_token_id
+            .get(&token_id)
+            .expect("Key not found");
+
+        let cur_key_use = get_key_cur_use(&drop, &key_info) - 1;
+        let claim_gate = get_asset_data_for_specific_use(&drop.asset_data_for_uses, &cur_key_use)
+            .config
+            .and_then(|c| c.claim_gate)
+            .expect("No claim gate found for use");
+
+        if claim_gate_passed(&claim_gate) {
+            near_sdk::log!("Claim gate passed for {}", receiver_id);
+            return self.internal_claim_pending_assets(
+                token_id,
+                receiver_id,
+                fc_args,
+                new_public_key,
+                referrer_id,
+                pending_claim,
+            );
+        }
+
+        near_sdk::log!(
+            "Claim gate failed for {}. Giving the use back to the key.",
+            receiver_id
+        );
+        drop.update_tokens_required(1, key_info.remaining_uses, key_info.remaining_uses + 1);
+        key_info.remaining_uses += 1;
+        key_info.uses_consumed -= 1;
+        key_info.last_claimed = pending_claim.previous_last_claimed;
+        drop.key_info_by_token_id.insert(&token_id, &key_info);
+        self.drop_by_id.insert(&drop_id, &drop);
+
+        if let Some(funding) = pending_claim.implicit_account_funding {
+            self.internal_modify_user_balance(&drop.funder_id, funding.0, false);
+        }
+        if let Some(reservation) = pending_claim.pay_it_forward_reservation {
+            self.internal_release_pay_it_forward_keys(reservation);
+        }
+
+        PromiseOrValue::Value(false)
+    }
+
+    /// Use whatever the claim set aside and then claim the assets. If an implicit account is being claimed to,
+    /// The transfer that creates it goes out before any of the assets. Any pay it forward keys are minted
+    /// To the receiver before the assets go out.
+    fn internal_claim_pending_assets(
+        &mut self,
+        token_id: TokenId,
+        receiver_id: AccountId,
+        fc_args: Option<UserProvidedFCArgs>,
+        new_public_key: Option<PublicKey>,
+        referrer_id: Option<AccountId>,
+        pending_claim: PendingClaim,
+    ) -> PromiseOrValue<bool> {
+        if let Some(funding) = pending_claim.implicit_account_funding {
+            Promise::new(receiver_id.clone()).transfer(NearToken::from_yoctonear(funding.0));
+        }
+        if let Some(reservation) = pending_claim.pay_it_forward_reservation {
+            let mut event_logs = Vec::new();
+            self.internal_mint_pay_it_forward_keys(&mut event_logs, &receiver_id, reservation);
+            log_events(event_logs);
+        }
+
+        self.internal_claim_assets(token_id, receiver_id, fc_args, new_public_key, referrer_id)
+    }
+}
+
+/// Check the result of the eligibility check against what the gate expects
+pub(crate) fn claim_gate_passed(claim_gate: &ClaimGate) -> bool {
+    let value = match env::promise_result(0) {
+        PromiseResult::Successful(value) => value,
+        PromiseResult::Failed => return false,
+    };
+
+    match claim_gate {
+        ClaimGate::nft_holder { .. } => near_sdk::serde_json::from_slice::<U128>(&value)
+            .map(|supply| supply.0 > 0)
+            .unwrap_or(false),
+        ClaimGate::ft_balance { min_balance, .. } => {
+            near_sdk::serde_json::from_slice::<U128>(&value)
+                .map(|balance| balance.0 >= min_balance.0)
+                .unwrap_or(false)
+        }
+        ClaimGate::custom { .. } => {
+            near_sdk::serde_json::from_slice::<bool>(&value).unwrap_or(false)
+        }
+    }
+}
+
+/// Ensure that the claim gate passed in is valid
+pub(crate) fn assert_valid_claim_gate(claim_gate: &ClaimGate) {
+    let contract_id = match claim_gate {
+        ClaimGate::nft_holder { contract_id } => contract_id,
+        ClaimGate::ft_balance { contract_id, .. } => contract_id,
+        ClaimGate::custom {
+            contract_id,
+            method_name,
+        } => {
+            if let Some(method_name) = method_name {
+                require!(
+                    !DEFAULT_PROHIBITED_FC_METHODS.contains(&method_name.as_str()),
+                    format!("Method {} cannot be used as a claim gate", method_name)
+                );
+            }
+            contract_id
+        }
+    };
+
+    require!(
+        contract_id != &env::current_account_id(),
+        "Claim gate cannot be the current Keypom contract."
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use near_sdk::PromiseResult;
+
+    use crate::test_utils::*;
+    use crate::*;
+
+    /// Claim a gated use that pays forward a key and resolve the gate check.
+    /// Returns the contract along with how much the drop had reserved for pay it forward keys before the claim
+    fn claim_gated_use(gate_passed: bool) -> (Keypom, Balance) {
+        let mut contract = setup_contract();
+        create_drop(
+            &mut contract,
+            "gated_drop",
+            &[public_key(1)],
+            None,
+            json!([{
+                "uses": 1,
+                "assets": [null],
+                "config": {
+                    "claim_gate": { "custom": { "contract_id": "gate.near" } },
+                    "pay_it_forward": { "max_keys_per_claim": 1, "total_key_budget": 1 }
+                }
+            }]),
+            json!(null),
+        );
+        let reserve = contract
+            .drop_by_id
+            .get(&"gated_drop".to_string())
+            .unwrap()
+            .pay_it_forward_reserve;
+
+        sign_with_key(&public_key(1));
+        contract.claim(
+            "claimer.near".parse().unwrap(),
+            None,
+            None,
+            None,
+            Some(vec![public_key(2)]),
+        );
+
+        // The key is taken out of the budget but isn't minted until the gate passes
+        let drop = contract.drop_by_id.get(&"gated_drop".to_string()).unwrap();
+        assert_eq!(drop.pay_it_forward_keys_minted, 1);
+        assert!(drop.pay_it_forward_reserve < reserve);
+        assert!(contract.token_id_by_pk.get(&public_key(2)).is_none());
+
+        let args = scheduled_call_args("on_claim_gate_checked");
+        resolve_callback(if gate_passed {
+            PromiseResult::Successful(b"true".to_vec())
+        } else {
+            PromiseResult::Failed
+        });
+        contract.on_claim_gate_checked(
+            serde_json::from_value(args["token_id"].clone()).unwrap(),
+            serde_json::from_value(args["receiver_id"].clone()).unwrap(),
+            None,
+            None,
+            None,
+            serde_json::from_value(args["pending_claim"].clone()).unwrap(),
+        );
+
+        (contract, reserve)
+    }
+
+    #[test]
+    fn pay_it_forward_keys_are_released_when_the_gate_fails() {
+        let (contract, reserve) = claim_gated_use(false);
+
+        let drop = contract.drop_by_id.get(&"gated_drop".to_string()).unwrap();
+        assert_eq!(drop.pay_it_forward_keys_minted, 0);
+        assert_eq!(drop.pay_it_forward_reserve, reserve);
+        assert!(contract.token_id_by_pk.get(&public_key(2)).is_none());
+        assert_eq!(contract.get_user_balance(funder_id()).0, 0);
+
+        // The use is given back to the key
+        let key_info = drop
+            .key_info_by_token_id
+            .get(&"gated_drop:0".to_string())
+            .unwrap();
+        assert_eq!(key_info.remaining_uses, 1);
+    }
+
+    #[test]
+    fn pay_it_forward_keys_are_minted_when_the_gate_passes() {
+        let (contract, _) = claim_gated_use(true);
+
+        let token_id = contract
+            .token_id_by_pk
+            .get(&public_key(2))
+            .expect("Key was not minted");
+        let owner_tokens = contract
+            .tokens_per_owner
+            .get(&"claimer.near".parse().unwrap())
+            .unwrap();
+        assert!(owner_tokens.contains(&token_id));
+        assert_eq!(
+            contract
+                .drop_by_id
+                .get(&"gated_drop".to_string())
+                .unwrap()
+                .pay_it_forward_keys_minted,
+            1
+        );
+        // Whatever storage the key didn't use is given back to the funder
+        assert!(contract.get_user_balance(funder_id()).0 > 0);
+    }
+}
@@ -8,8 +8,11 @@ impl Keypom {
         account_id: AccountId,
         fc_args: Option<UserProvidedFCArgs>,
         password: Option<String>,
+        referrer_id: Option<AccountId>,
+        pay_it_forward_keys: Option<Vec<PublicKey>>,
     ) -> PromiseOrValue<bool> {
         self.assert_no_global_freeze();
+        assert_valid_referrer(&referrer_id, &account_id);
 
         let mut event_logs: Vec<EventLog> = Vec::new();
         let BeforeClaimData {
@@ -17,12 +20,18 @@ impl Keypom {
             required_asset_gas,
             root_account_id: _,
             account_creation_keypom_args: _,
-            drop_id: _,
+            pay_it_forward,
+            drop_id,
             key_id: _,
             funder_id: _,
-        } = self.before_claim_logic(&mut event_logs, None, password);
+            is_gated: _,
+            previous_last_claimed,
+        } = self.before_claim_logic(&mut event_logs, None, None, password);
         let prepaid_gas = env::prepaid_gas();
-        let total_required_gas = BASE_GAS_FOR_CLAIM.as_gas() + required_asset_gas.as_gas();
+        let pay_it_forward_keys = pay_it_forward_keys.unwrap_or_default();
+        let total_required_gas = BASE_GAS_FOR_CLAIM.as_gas()
+            + required_asset_gas.as_gas()
+            + pay_it_forward_keys.len() as u64 * GAS_PER_PAY_IT_FORWARD_KEY.as_gas();
         // Use to check prepaid == required. Changed to >= for the sake of simplicity for now
         // Future plan to implement required_gas_for_claim and required_gas_for_create_account_and_claim into key info
         require!(
@@ -34,8 +43,108 @@ impl Keypom {
             )
         );
 
+        // Take the keys out of the budget now. They're minted right before the assets go out
+        // Or given back if the claim gate fails
+        let pay_it_forward_reservation = self.internal_reserve_pay_it_forward_keys(
+            &drop_id,
+            pay_it_forward.as_ref(),
+            pay_it_forward_keys,
+        );
+
+        log_events(event_logs);
+        self.internal_gated_claim_assets(
+            token_id,
+            account_id,
+            fc_args,
+            None,
+            referrer_id,
+            PendingClaim {
+                previous_last_claimed,
+                implicit_account_funding: None,
+                pay_it_forward_reservation,
+            },
+        )
+    }
+
+    /// Claim the assets to the implicit account derived from `new_public_key`.
+    /// ED25519 keys result in a NEAR-implicit account and SECP256K1 keys in an ETH-implicit account.
+    /// Implicit accounts are created by the first transfer they receive so there is no `create_account` call
+    /// to the root account and the assets are claimed right away. If the use doesn't send any $NEAR, the
+    /// funder's balance covers a small transfer to create the account before the assets go out.
+    #[private]
+    pub fn create_implicit_account_and_claim(
+        &mut self,
+        new_public_key: PublicKey,
+        fc_args: Option<UserProvidedFCArgs>,
+        password: Option<String>,
+        referrer_id: Option<AccountId>,
+        pay_it_forward_keys: Option<Vec<PublicKey>>,
+    ) -> PromiseOrValue<bool> {
+        self.assert_no_global_freeze();
+
+        let new_account_id = derive_implicit_account_id(&new_public_key);
+        near_sdk::log!("Claiming to implicit account: {}", new_account_id);
+        assert_valid_referrer(&referrer_id, &new_account_id);
+
+        let mut event_logs = Vec::new();
+        let BeforeClaimData {
+            token_id,
+            required_asset_gas,
+            root_account_id: _,
+            account_creation_keypom_args: _,
+            pay_it_forward,
+            drop_id,
+            key_id: _,
+            funder_id,
+            is_gated: _,
+            previous_last_claimed,
+        } = self.before_claim_logic(
+            &mut event_logs,
+            Some(&new_public_key),
+            // Implicit account IDs are derived from the key so naming rules can't apply to them
+            None,
+            password,
+        );
+
+        let prepaid_gas = env::prepaid_gas();
+        let pay_it_forward_keys = pay_it_forward_keys.unwrap_or_default();
+        let total_required_gas = BASE_GAS_FOR_CREATE_IMPLICIT_ACC_AND_CLAIM.as_gas()
+            + required_asset_gas.as_gas()
+            + pay_it_forward_keys.len() as u64 * GAS_PER_PAY_IT_FORWARD_KEY.as_gas();
+        require!(
+            prepaid_gas.as_gas() >= total_required_gas,
+            format!(
+                "Not enough gas attached. Required: {}, Prepaid: {}",
+                total_required_gas,
+                prepaid_gas.as_gas()
+            )
+        );
+
+        let pay_it_forward_reservation = self.internal_reserve_pay_it_forward_keys(
+            &drop_id,
+            pay_it_forward.as_ref(),
+            pay_it_forward_keys,
+        );
+
+        // The funder covers the transfer that creates the account if the use doesn't send any $NEAR
+        let implicit_account_funding = self.get_implicit_account_funding(&token_id);
+        if let Some(funding) = implicit_account_funding {
+            self.internal_modify_user_balance(&funder_id, funding, true);
+        }
+
         log_events(event_logs);
-        self.internal_claim_assets(token_id, account_id, fc_args, None)
+        self.internal_gated_claim_assets(
+            token_id,
+            new_account_id,
+            fc_args,
+            Some(new_public_key),
+            referrer_id,
+            PendingClaim {
+                previous_last_claimed,
+                implicit_account_funding: implicit_account_funding.map(U128),
+                pay_it_forward_reservation,
+            },
+        )
     }
 
     #[private]
@@ -45,8 +154,11 @@ impl Keypom {
         new_public_key: PublicKey,
         fc_args: Option<UserProvidedFCArgs>,
         password: Option<String>,
+        referrer_id: Option<AccountId>,
+        pay_it_forward_keys: Option<Vec<PublicKey>>,
     ) -> Promise {
         self.assert_no_global_freeze();
+        assert_valid_referrer(&referrer_id, &new_account_id);
 
         let mut event_logs = Vec::new();
         let BeforeClaimData {
@@ -54,14 +166,29 @@ impl Keypom {
             required_asset_gas,
             root_account_id,
             account_creation_keypom_args,
+            pay_it_forward,
             drop_id,
             key_id,
             funder_id,
-        } = self.before_claim_logic(&mut event_logs, Some(&new_public_key), password);
+            is_gated,
+            previous_last_claimed: _,
+        } = self.before_claim_logic(
+            &mut event_logs,
+            Some(&new_public_key),
+            Some(&new_account_id),
+            password,
+        );
+        // The account doesn't exist until after the use is taken so it could never hold what the gate checks for
+        require!(
+            !is_gated,
+            "Gated uses must be claimed to an existing account using claim"
+        );
 
         let prepaid_gas = env::prepaid_gas();
-        let total_required_gas =
-            BASE_GAS_FOR_CREATE_ACC_AND_CLAIM.as_gas() + required_asset_gas.as_gas();
+        let pay_it_forward_keys = pay_it_forward_keys.unwrap_or_default();
+        let total_required_gas = BASE_GAS_FOR_CREATE_ACC_AND_CLAIM.as_gas()
+            + required_asset_gas.as_gas()
+            + pay_it_forward_keys.len() as u64 * GAS_PER_PAY_IT_FORWARD_KEY.as_gas();
         require!(
             prepaid_gas.as_gas() >= total_required_gas,
             format!(
@@ -70,10 +197,20 @@ impl Keypom {
                 prepaid_gas.as_gas()
             )
         );
-        let gas_for_callback =
-            BASE_GAS_FOR_RESOLVE_ACCOUNT_CREATION.as_gas() + required_asset_gas.as_gas();
+        // Pay it forward keys are minted once the account has been created
+        let gas_for_callback = BASE_GAS_FOR_RESOLVE_ACCOUNT_CREATION.as_gas()
+            + required_asset_gas.as_gas()
+            + pay_it_forward_keys.len() as u64 * GAS_PER_PAY_IT_FORWARD_KEY.as_gas();
         near_sdk::log!("gas_for_callback: {}", gas_for_callback);
 
+        // Take the keys out of the budget now so concurrent claims can't go over it.
+        // They're given back if the account can't be created
+        let pay_it_forward_reservation = self.internal_reserve_pay_it_forward_keys(
+            &drop_id,
+            pay_it_forward.as_ref(),
+            pay_it_forward_keys,
+        );
+
         log_events(event_logs);
         near_sdk::log!(
             "Keypom Args Before create_account: {:?}",
@@ -110,7 +247,85 @@ impl Keypom {
                 Self::ext(env::current_account_id())
                     .with_static_gas(Gas::from_gas(gas_for_callback))
                     .with_unused_gas_weight(1)
-                    .on_new_account_created(token_id, new_account_id, fc_args, new_public_key),
+                    .on_new_account_created(
+                        token_id,
+                        new_account_id,
+                        fc_args,
+                        new_public_key,
+                        referrer_id,
+                        pay_it_forward_reservation,
+                    ),
             )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::*;
+    use crate::*;
+
+    /// Create a drop whose key is claimed to the implicit account for *new_public_key* and claim it
+    fn claim_to_implicit_account(
+        contract: &mut Keypom,
+        assets: serde_json::Value,
+        new_public_key: &PublicKey,
+    ) {
+        create_drop(
+            contract,
+            "implicit_drop",
+            &[public_key(1)],
+            None,
+            json!([{
+                "uses": 2,
+                "assets": assets,
+                "config": { "account_name_rules": { "prefix": "keypom-" } }
+            }]),
+            json!(null),
+        );
+        sign_with_key(&public_key(1));
+        contract.create_implicit_account_and_claim(new_public_key.clone(), None, None, None, None);
+    }
+
+    #[test]
+    fn implicit_claims_without_near_are_funded_by_the_funder() {
+        let mut contract = setup_contract();
+        add_to_balance(&mut contract, &funder_id(), NearToken::from_near(1));
+
+        // The naming rules don't apply to the implicit account
+        let new_public_key = public_key(2);
+        claim_to_implicit_account(&mut contract, json!([null]), &new_public_key);
+        assert_eq!(
+            transfers_to(&derive_implicit_account_id(&new_public_key)),
+            vec![IMPLICIT_ACCOUNT_FUNDING]
+        );
+        assert_eq!(
+            contract.get_user_balance(funder_id()).0,
+            NearToken::from_near(1).as_yoctonear() - IMPLICIT_ACCOUNT_FUNDING
+        );
+    }
+
+    #[test]
+    fn implicit_claims_with_near_are_funded_by_the_use() {
+        let mut contract = setup_contract();
+
+        let new_public_key = public_key(2);
+        let yoctonear = NearToken::from_near(1).as_yoctonear();
+        claim_to_implicit_account(
+            &mut contract,
+            json!([{ "yoctonear": yoctonear.to_string() }]),
+            &new_public_key,
+        );
+        assert_eq!(
+            transfers_to(&derive_implicit_account_id(&new_public_key)),
+            vec![yoctonear]
+        );
+        assert_eq!(contract.get_user_balance(funder_id()).0, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "is less than required")]
+    fn implicit_claims_fail_if_the_funder_cannot_fund_the_account() {
+        let mut contract = setup_contract();
+        claim_to_implicit_account(&mut contract, json!([null]), &public_key(2));
+    }
+}
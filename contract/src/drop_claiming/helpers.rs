@@ -10,6 +10,7 @@ impl Keypom {
         &mut self,
         event_logs: &mut Vec<EventLog>,
         new_public_key: Option<&PublicKey>,
+        new_account_id: Option<&AccountId>,
         password: Option<String>,
     ) -> BeforeClaimData {
         let signer_pk = env::signer_account_pk();
@@ -39,72 +40,154 @@ impl Keypom {
             required_asset_gas,
         } = get_asset_data_for_specific_use(&drop.asset_data_for_uses, &cur_key_use);
 
+        // Crowdfunded drops can only be claimed once the target has been met
+        require!(
+            drop.get_crowdfund_status()
+                .map(|status| status == CrowdfundStatus::funded)
+                .unwrap_or(true),
+            "Drop has not met its crowdfund target"
+        );
+
         assert_pre_claim_conditions(
             &key_info,
             &use_config,
             &password,
             &cur_key_use,
-            &drop.max_key_uses,
             new_public_key.is_some(),
         );
 
+        let root_account_id = use_config
+            .as_ref()
+            .and_then(|c| c.root_account_id.clone())
+            .unwrap_or(self.root_account.clone());
+
+        // Named accounts must follow the drop's naming rules before any gas is spent creating them
+        if let Some(account_id) = new_account_id {
+            assert_valid_new_account_name(
+                account_id,
+                &root_account_id,
+                use_config
+                    .as_ref()
+                    .and_then(|c| c.account_name_rules.as_ref()),
+                drop.config
+                    .as_ref()
+                    .and_then(|c| c.banned_account_words.as_ref()),
+            );
+        }
+
+        require!(key_info.remaining_uses > 0, "Key has no uses remaining");
+        drop.update_tokens_required(1, key_info.remaining_uses, key_info.remaining_uses - 1);
         key_info.remaining_uses -= 1;
+        key_info.uses_consumed += 1;
+
+        // Claim time is recorded right away so that throttles hold while a gate is being checked.
+        // Gated uses are only finalized once the gate passes and both the use and claim time
+        // Are given back if it fails (see `on_claim_gate_checked`)
+        let previous_last_claimed = key_info.last_claimed;
         key_info.last_claimed = env::block_timestamp();
-        if key_info.remaining_uses == 0 {
+        let is_gated = use_config
+            .as_ref()
+            .and_then(|c| c.claim_gate.as_ref())
+            .is_some();
+        if !is_gated && key_info.remaining_uses == 0 {
             // Delete everything except the token ID -> key info mapping since we need the key info in callbacks
-            if let Some(owner) = key_info.owner_id.as_ref() {
-                self.internal_remove_token_from_owner(owner, &token_id);
-            }
-
-            self.token_id_by_pk.remove(&signer_pk);
-            Promise::new(env::current_account_id()).delete_key(signer_pk.clone());
-
-            event_logs.push(EventLog {
-                standard: NFT_STANDARD_NAME.to_string(),
-                version: NFT_METADATA_SPEC.to_string(),
-                event: EventLogVariant::NftBurn(vec![NftBurnLog {
-                    owner_id: key_info
-                        .owner_id
-                        .as_ref()
-                        .unwrap_or(&env::current_account_id())
-                        .to_string(),
-                    token_ids: vec![token_id.to_string()],
-                    authorized_id: None,
-                    memo: None,
-                }]),
-            });
-            event_logs.push(EventLog {
-                standard: KEYPOM_STANDARD_NAME.to_string(),
-                version: KEYPOM_STANDARD_VERSION.to_string(),
-                event: EventLogVariant::DeleteKey(vec![AddOrDeleteKeyLog {
-                    drop_id: drop_id.to_string(),
-                    public_key: (&signer_pk).into(),
-                }]),
-            });
+            self.internal_finalize_used_key(&key_info, &token_id, &drop_id, event_logs);
         }
 
         drop.key_info_by_token_id.insert(&token_id, &key_info);
         self.drop_by_id.insert(&drop_id, &drop);
 
-        let root_account_id = use_config
-            .as_ref()
-            .and_then(|c| c.root_account_id.clone())
-            .unwrap_or(self.root_account.clone());
         let account_creation_keypom_args = use_config
             .as_ref()
             .and_then(|c| c.account_creation_keypom_args.clone());
+        let pay_it_forward = use_config.and_then(|c| c.pay_it_forward);
 
         BeforeClaimData {
             token_id,
             required_asset_gas,
             root_account_id,
             account_creation_keypom_args,
+            pay_it_forward,
             key_id,
             drop_id,
             funder_id: drop.funder_id,
+            is_gated,
+            previous_last_claimed,
+        }
+    }
+
+    /// How much the funder needs to transfer to create the implicit account that the key's current use is
+    /// Being claimed to. Returns None if the use already sends $NEAR since that transfer creates the account.
+    pub(crate) fn get_implicit_account_funding(&self, token_id: &TokenId) -> Option<Balance> {
+        let (drop_id, _) = parse_token_id(token_id).unwrap();
+        let drop: InternalDrop = self.drop_by_id.get(&drop_id).expect("Drop not found");
+        let key_info = drop
+            .key_info_by_token_id
+            .get(token_id)
+            .expect("Key not found");
+
+        // The uses were decremented before the claim, so we need to increment them back to get the current use
+        let cur_key_use = get_key_cur_use(&drop, &key_info) - 1;
+        let sends_near = get_asset_data_for_specific_use(&drop.asset_data_for_uses, &cur_key_use)
+            .assets_metadata
+            .iter()
+            .any(|metadata| {
+                metadata.asset_id == NEAR_ASSET_ID
+                    && metadata.tokens_per_use.map(|t| t.0).unwrap_or(0) > 0
+            });
+
+        if sends_near {
+            None
+        } else {
+            Some(IMPLICIT_ACCOUNT_FUNDING)
         }
     }
 
+    /// Break the connection between a key with no uses left and its public key so it can't be used anymore.
+    /// This deletes the access key, removes the token from its owner and logs the burn events.
+    /// Does nothing if the key has already been finalized.
+    pub(crate) fn internal_finalize_used_key(
+        &mut self,
+        key_info: &InternalKeyInfo,
+        token_id: &TokenId,
+        drop_id: &DropId,
+        event_logs: &mut Vec<EventLog>,
+    ) {
+        if self.token_id_by_pk.get(&key_info.pub_key).as_ref() != Some(token_id) {
+            return;
+        }
+
+        if let Some(owner) = key_info.owner_id.as_ref() {
+            self.internal_remove_token_from_owner(owner, token_id);
+        }
+
+        self.token_id_by_pk.remove(&key_info.pub_key);
+        Promise::new(env::current_account_id()).delete_key(key_info.pub_key.clone());
+
+        event_logs.push(EventLog {
+            standard: NFT_STANDARD_NAME.to_string(),
+            version: NFT_METADATA_SPEC.to_string(),
+            event: EventLogVariant::NftBurn(vec![NftBurnLog {
+                owner_id: key_info
+                    .owner_id
+                    .as_ref()
+                    .unwrap_or(&env::current_account_id())
+                    .to_string(),
+                token_ids: vec![token_id.to_string()],
+                authorized_id: None,
+                memo: None,
+            }]),
+        });
+        event_logs.push(EventLog {
+            standard: KEYPOM_STANDARD_NAME.to_string(),
+            version: KEYPOM_STANDARD_VERSION.to_string(),
+            event: EventLogVariant::DeleteKey(vec![AddOrDeleteKeyLog {
+                drop_id: drop_id.to_string(),
+                public_key: (&key_info.pub_key).into(),
+            }]),
+        });
+    }
+
     /// Internal function that loops through all assets for the given use and claims them.
     /// Should be executed in both `claim` or `create_account_and_claim`
     /// Once all assets are claimed, a cross-contract call is fired to `on_assets_claimed`
@@ -114,6 +197,7 @@ impl Keypom {
         receiver_id: AccountId,
         fc_args: Option<UserProvidedFCArgs>,
         new_public_key: Option<PublicKey>,
+        referrer_id: Option<AccountId>,
     ) -> PromiseOrValue<bool> {
         let (drop_id, key_id) = parse_token_id(&token_id).unwrap();
 
@@ -219,11 +303,11 @@ impl Keypom {
                     Self::ext(env::current_account_id())
                         //.with_static_gas(MIN_GAS_FOR_RESOLVE_ASSET_CLAIM)
                         .with_unused_gas_weight(1)
-                        .on_assets_claimed(token_id, token_ids_transferred),
+                        .on_assets_claimed(token_id, token_ids_transferred, referrer_id),
                 ),
             )
         } else {
-            self.on_assets_claimed(token_id, token_ids_transferred)
+            self.on_assets_claimed(token_id, token_ids_transferred, referrer_id)
         }
     }
 
@@ -242,6 +326,9 @@ impl Keypom {
         // Now that the callback is finished, we can remove the key info from the drop
         // Since no other functions need the key information
         if key_info.remaining_uses == 0 {
+            // Gated keys are only finalized once their last use has been resolved
+            self.internal_finalize_used_key(key_info, token_id, drop_id, &mut event_logs);
+
             drop.key_info_by_token_id
                 .remove(&token_id)
                 .expect("Key not found");
@@ -263,6 +350,12 @@ impl Keypom {
                 self.drop_by_id.remove(&drop_id);
                 self.internal_remove_drop_for_funder(&drop.funder_id, &drop_id);
                 internal_clear_drop_storage(drop, &mut event_logs, &drop_id);
+                // Nothing else can be paid forward so the rest of the reserve goes back to the funder
+                self.internal_modify_user_balance(
+                    &drop.funder_id,
+                    drop.pay_it_forward_reserve,
+                    false,
+                );
             } else {
                 near_sdk::log!("Drop with ID: {} is not empty. Re-inserting. Does have assets? {} Config specified to delete: {}", drop_id, !drop_assets_withdrawn, should_delete_on_empty);
                 // Put the modified drop back in storage
@@ -301,7 +394,6 @@ pub(crate) fn assert_pre_claim_conditions(
     use_config: &Option<UseConfig>,
     user_password: &Option<String>,
     cur_key_use: &UseNumber,
-    max_uses_per_key: &UseNumber,
     creating_account: bool,
 ) {
     // Ensure that claim and create_account_and_claim are only called based on the key / drop's config
@@ -321,7 +413,6 @@ pub(crate) fn assert_pre_claim_conditions(
         use_config,
         key_info,
         &String::from(&env::signer_account_pk()),
-        max_uses_per_key,
     );
 
     // If there is some password for the current key use, assert that it matches the one provided
@@ -332,6 +423,75 @@ pub(crate) fn assert_pre_claim_conditions(
     }
 }
 
+/// Internal function to assert that a new account ID follows the naming rules and doesn't contain any banned words.
+/// All checks are performed on the name of the account without the root (i.e `benji` for `benji.fayyr.near`)
+pub(crate) fn assert_valid_new_account_name(
+    new_account_id: &AccountId,
+    root_account_id: &AccountId,
+    rules: Option<&AccountNameRules>,
+    banned_words: Option<&Vec<String>>,
+) {
+    let root_suffix = format!(".{}", root_account_id);
+    let name = new_account_id
+        .as_str()
+        .strip_suffix(&root_suffix)
+        .unwrap_or(new_account_id.as_str());
+
+    if let Some(rules) = rules {
+        if let Some(prefix) = rules.prefix.as_ref() {
+            require!(
+                name.starts_with(prefix.as_str()),
+                format!("Account name {} must start with {}", name, prefix)
+            );
+        }
+
+        if let Some(suffix) = rules.suffix.as_ref() {
+            require!(
+                name.ends_with(suffix.as_str()),
+                format!("Account name {} must end with {}", name, suffix)
+            );
+        }
+
+        let name_length = name.len() as u32;
+        require!(
+            name_length >= rules.min_length.unwrap_or(0)
+                && name_length <= rules.max_length.unwrap_or(u32::MAX),
+            format!(
+                "Account name {} must be between {} and {} characters",
+                name,
+                rules.min_length.unwrap_or(0),
+                rules.max_length.unwrap_or(u32::MAX)
+            )
+        );
+
+        if let Some(charset) = rules.charset.as_ref() {
+            let is_valid = match charset {
+                AccountNameCharset::alphanumeric => name
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit()),
+                AccountNameCharset::alphabetic => name.chars().all(|c| c.is_ascii_lowercase()),
+                AccountNameCharset::numeric => name.chars().all(|c| c.is_ascii_digit()),
+            };
+            require!(
+                is_valid,
+                format!(
+                    "Account name {} contains characters that are not allowed",
+                    name
+                )
+            );
+        }
+    }
+
+    if let Some(banned_words) = banned_words {
+        for word in banned_words {
+            require!(
+                !name.contains(word.to_lowercase().as_str()),
+                format!("Account name {} contains a banned word", name)
+            );
+        }
+    }
+}
+
 /// Internal function to assert that the password for claim matches the one in the key info
 pub(crate) fn assert_key_password(user_password: &Option<String>, expected_password: Vec<u8>) {
     let hashed_user_pw = sha256(
@@ -355,9 +515,12 @@ pub(crate) fn assert_claim_timestamps(
     use_config: &Option<UseConfig>,
     key_info: &InternalKeyInfo,
     signer_pk: &String,
-    max_uses_per_key: &UseNumber,
 ) {
-    let time_config = use_config.as_ref().and_then(|c| c.time.as_ref());
+    // The key's own time config takes precedence over the use's
+    let time_config = key_info
+        .time_config
+        .as_ref()
+        .or_else(|| use_config.as_ref().and_then(|c| c.time.as_ref()));
 
     if let Some(time_data) = time_config {
         // Ensure enough time has passed if a start timestamp was specified in the config.
@@ -400,14 +563,19 @@ pub(crate) fn assert_claim_timestamps(
             // At this moment, what is the maximum number of uses that COULD have been possibly claimed
             let total_possible_claims = (env::block_timestamp() - start_timestamp) / interval;
 
-            // How many claims given the interval and remaining uses does this key have left?
-            // Take the current total claims possible, and subtract the number of uses the key has left
+            // How many claims given the interval and consumed uses does this key have left?
+            // Take the current total claims possible, and subtract the number of uses the key has consumed
             // Example: If the interval is 1 day, and 4 days have passed, the key has 4 total claimable uses.
             // If the key has already been claimed 3/4 times, then the key has 1 remaining claimable uses.
-            let claims_so_far = max_uses_per_key - key_info.remaining_uses;
+            let claims_so_far = key_info.uses_consumed;
             let num_claimable_uses = total_possible_claims - claims_so_far as u64;
 
             require!(num_claimable_uses > 0, format!("Key {} has been claimed {} times. Given the interval {} and starting timestamp of {}, there are {} claims available.", signer_pk, claims_so_far, interval, start_timestamp, num_claimable_uses));
         }
+
+        // Ensure the key is within a claim window if it follows a recurring schedule
+        if let Some(schedule) = time_data.schedule.as_ref() {
+            assert_recurring_schedule(schedule, time_data.start.unwrap(), key_info, signer_pk);
+        }
     }
 }
@@ -1,5 +1,11 @@
 pub mod claims;
 pub mod claim_callbacks;
+pub mod claim_gates;
 pub mod helpers;
+pub mod referrals;
+pub mod schedules;
 
-pub use helpers::*;
\ No newline at end of file
+pub(crate) use claim_gates::*;
+pub use helpers::*;
+pub(crate) use referrals::*;
+pub(crate) use schedules::*;
\ No newline at end of file
@@ -0,0 +1,144 @@
+use near_sdk::PromiseResult;
+
+use crate::*;
+
+#[near_bindgen]
+impl Keypom {
+    /// Pay out the referral reward for a use once the claim has been resolved. If the claim failed, there is no referrer
+    /// Or the referrer has hit their limit, the reward's cost is given back to the funder instead.
+    /// Returns how many bytes of storage were added for a new referrer's counter
+    pub(crate) fn internal_handle_referral(
+        &mut self,
+        drop: &mut InternalDrop,
+        drop_id: &DropId,
+        referral: &ReferralConfig,
+        referrer_id: Option<AccountId>,
+        was_successful: bool,
+    ) -> u64 {
+        let asset_id = get_referral_asset_id(&referral.reward);
+        let mut asset: InternalAsset = drop
+            .asset_by_id
+            .get(&asset_id)
+            .expect("Referral asset not found");
+        let tokens_per_use = referral.reward.get_tokens_per_use().0;
+
+        // Everything that was reserved for this reward when the key was added
+        let reserved_storage_cost =
+            REFERRAL_STORAGE_RESERVE_BYTES as u128 * env::storage_byte_cost().as_yoctonear();
+        let reserved_cost =
+            asset.get_yocto_refund_amount(&Some(tokens_per_use)) + reserved_storage_cost;
+
+        let referrer_id = match referrer_id {
+            Some(referrer_id) if was_successful => referrer_id,
+            _ => {
+                self.internal_modify_user_balance(&drop.funder_id, reserved_cost, false);
+                return 0;
+            }
+        };
+
+        let num_rewards = drop.referrals_by_account.get(&referrer_id).unwrap_or(0);
+        if let Some(max_rewards) = referral.max_rewards_per_referrer {
+            if num_rewards >= max_rewards {
+                near_sdk::log!(
+                    "Referrer {} has already earned the maximum of {} rewards",
+                    referrer_id,
+                    max_rewards
+                );
+                self.internal_modify_user_balance(&drop.funder_id, reserved_cost, false);
+                return 0;
+            }
+        }
+
+        let promise = match asset.claim_asset(
+            &referrer_id,
+            &Some(tokens_per_use),
+            None,
+            drop_id.clone(),
+            String::new(),
+            drop.funder_id.clone(),
+        ) {
+            Some(promise) => promise,
+            None => {
+                near_sdk::log!("Referral reward could not be paid out. Skipping.");
+                self.internal_modify_user_balance(&drop.funder_id, reserved_cost, false);
+                return 0;
+            }
+        };
+        drop.asset_by_id.insert(&asset_id, &asset);
+
+        // Increment the counter before the reward is resolved so the limit can't be exceeded by concurrent claims
+        let initial_storage = env::storage_usage();
+        drop.referrals_by_account
+            .insert(&referrer_id, &(num_rewards + 1));
+        let storage_added = env::storage_usage() - initial_storage;
+
+        // The storage reserve covers the new counter. Anything left over goes back to the funder
+        let storage_cost = storage_added as u128 * env::storage_byte_cost().as_yoctonear();
+        self.internal_modify_user_balance(
+            &drop.funder_id,
+            reserved_storage_cost.saturating_sub(storage_cost),
+            false,
+        );
+
+        promise.then(
+            Self::ext(env::current_account_id())
+                .with_static_gas(MIN_GAS_FOR_RECEIPT_COMPUTATION)
+                .on_referral_reward_claimed(
+                    drop_id.clone(),
+                    asset_id,
+                    referrer_id,
+                    U128(tokens_per_use),
+                ),
+        );
+
+        storage_added
+    }
+
+    /// Resolve the payout of a referral reward. If the payout failed, the tokens are put back into
+    /// The referral asset, the funder is refunded and the referrer's counter is decremented
+    #[private]
+    pub fn on_referral_reward_claimed(
+        &mut self,
+        drop_id: DropId,
+        asset_id: AssetId,
+        referrer_id: AccountId,
+        tokens_per_use: U128,
+    ) -> bool {
+        if let PromiseResult::Successful(_) = env::promise_result(0) {
+            return true;
+        }
+
+        near_sdk::log!("Referral reward for {} failed", referrer_id);
+
+        // The drop may have been deleted in the meantime in which case there is nothing to put back
+        let mut drop = match self.drop_by_id.get(&drop_id) {
+            Some(drop) => drop,
+            None => return false,
+        };
+
+        if let Some(mut asset) = drop.asset_by_id.get(&asset_id) {
+            let amount_to_increment = asset.on_failed_claim(&Some(tokens_per_use.0.to_string()));
+            self.internal_modify_user_balance(&drop.funder_id, amount_to_increment, false);
+            drop.asset_by_id.insert(&asset_id, &asset);
+        }
+
+        if let Some(num_rewards) = drop.referrals_by_account.get(&referrer_id) {
+            drop.referrals_by_account
+                .insert(&referrer_id, &num_rewards.saturating_sub(1));
+        }
+
+        self.drop_by_id.insert(&drop_id, &drop);
+
+        false
+    }
+}
+
+/// Ensure that the referrer (if any) isn't the account claiming the assets
+pub(crate) fn assert_valid_referrer(referrer_id: &Option<AccountId>, receiver_id: &AccountId) {
+    if let Some(referrer_id) = referrer_id {
+        require!(
+            referrer_id != receiver_id,
+            "Cannot refer yourself when claiming"
+        );
+    }
+}
@@ -0,0 +1,193 @@
+use crate::*;
+
+/// Number of nanoseconds in a day. Periods in recurring schedules always begin at 0:00 UTC
+pub(crate) const NANOS_PER_DAY: u64 = 86_400 * 1_000_000_000;
+
+/// Internal function to assert that a key with a recurring schedule can be claimed at the current block timestamp
+pub(crate) fn assert_recurring_schedule(
+    schedule: &RecurringSchedule,
+    start_timestamp: u64,
+    key_info: &InternalKeyInfo,
+    signer_pk: &String,
+) {
+    let current_timestamp = env::block_timestamp();
+    let claims_so_far = key_info.uses_consumed as u64;
+
+    let cur_period = get_current_period(schedule, start_timestamp, current_timestamp)
+        .unwrap_or_else(|| {
+            env::panic_str(&format!(
+                "Key {} isn't claimable until {}. Current timestamp {}",
+                signer_pk,
+                get_period_start(schedule, start_timestamp, 0),
+                current_timestamp
+            ))
+        });
+
+    // Make sure we're still within the window for the current period
+    let window_end = get_period_start(schedule, start_timestamp, cur_period)
+        .saturating_add(schedule.claim_window.unwrap_or(u64::MAX));
+    require!(
+        current_timestamp < window_end,
+        format!(
+            "Key {} can only be claimed until {} this period. Current timestamp {}",
+            signer_pk, window_end, current_timestamp
+        )
+    );
+
+    match schedule.missed_periods {
+        MissedPeriods::accumulate => {
+            // Every period that has begun (including the current one) adds a claim
+            require!(
+                claims_so_far < cur_period + 1,
+                format!(
+                    "Key {} has been claimed {} times and only {} periods have begun",
+                    signer_pk,
+                    claims_so_far,
+                    cur_period + 1
+                )
+            );
+        }
+        MissedPeriods::forfeit => {
+            // The key can only be claimed once per period
+            let claimed_this_period = claims_so_far > 0
+                && get_current_period(schedule, start_timestamp, key_info.last_claimed)
+                    == Some(cur_period);
+            require!(
+                !claimed_this_period,
+                format!("Key {} has already been claimed this period", signer_pk)
+            );
+        }
+    }
+}
+
+/// Get the next timestamp that a key with a recurring schedule can be claimed at. If the key can be
+/// Claimed right now, this is the beginning of the current period
+pub(crate) fn get_next_claimable_timestamp(
+    schedule: &RecurringSchedule,
+    start_timestamp: u64,
+    key_info: &InternalKeyInfo,
+) -> u64 {
+    let current_timestamp = env::block_timestamp();
+    let claims_so_far = key_info.uses_consumed as u64;
+
+    let cur_period = match get_current_period(schedule, start_timestamp, current_timestamp) {
+        Some(period) => period,
+        None => return get_period_start(schedule, start_timestamp, 0),
+    };
+    let cur_period_start = get_period_start(schedule, start_timestamp, cur_period);
+    let in_window = current_timestamp
+        < cur_period_start.saturating_add(schedule.claim_window.unwrap_or(u64::MAX));
+
+    let can_claim_this_period = match schedule.missed_periods {
+        MissedPeriods::accumulate => claims_so_far < cur_period + 1,
+        MissedPeriods::forfeit => {
+            claims_so_far == 0
+                || get_current_period(schedule, start_timestamp, key_info.last_claimed)
+                    != Some(cur_period)
+        }
+    };
+
+    if in_window && can_claim_this_period {
+        cur_period_start
+    } else {
+        get_period_start(schedule, start_timestamp, cur_period + 1)
+    }
+}
+
+/// Get the index of the period that the given timestamp falls in (the first period is 0).
+/// Returns None if the first period hasn't begun yet.
+pub(crate) fn get_current_period(
+    schedule: &RecurringSchedule,
+    start_timestamp: u64,
+    timestamp: u64,
+) -> Option<u64> {
+    let first_period_start = get_period_start(schedule, start_timestamp, 0);
+    if timestamp < first_period_start {
+        return None;
+    }
+
+    match schedule.period {
+        SchedulePeriod::weekly { .. } => {
+            Some((timestamp - first_period_start) / (7 * NANOS_PER_DAY))
+        }
+        SchedulePeriod::monthly { .. } => {
+            let (first_year, first_month, _) = civil_from_days(first_period_start / NANOS_PER_DAY);
+            let (year, month, _) = civil_from_days(timestamp / NANOS_PER_DAY);
+
+            // Count the months between the two and step back if this month's period hasn't begun yet
+            let mut period = (year - first_year) as u64 * 12 + month as u64 - first_month as u64;
+            if timestamp < get_period_start(schedule, start_timestamp, period) {
+                period -= 1;
+            }
+            Some(period)
+        }
+    }
+}
+
+/// Get the timestamp that the given period (the first period is 0) begins at
+pub(crate) fn get_period_start(
+    schedule: &RecurringSchedule,
+    start_timestamp: u64,
+    period: u64,
+) -> u64 {
+    let start_day = start_timestamp / NANOS_PER_DAY;
+
+    match schedule.period {
+        SchedulePeriod::weekly { day_of_week } => {
+            // January 1, 1970 was a Thursday (3 with Monday being 0)
+            let start_weekday = (start_day + 3) % 7;
+            let mut first_day = start_day + (day_of_week as u64 + 7 - start_weekday) % 7;
+            if first_day * NANOS_PER_DAY < start_timestamp {
+                first_day += 7;
+            }
+            (first_day + period * 7) * NANOS_PER_DAY
+        }
+        SchedulePeriod::monthly { day_of_month } => {
+            let (start_year, start_month, _) = civil_from_days(start_day);
+
+            // The first period is this month unless the day has already passed
+            let mut first_month_idx = start_year as u64 * 12 + (start_month as u64 - 1);
+            if days_from_civil(start_year, start_month, day_of_month as u32) * NANOS_PER_DAY
+                < start_timestamp
+            {
+                first_month_idx += 1;
+            }
+
+            let month_idx = first_month_idx + period;
+            let year = (month_idx / 12) as i64;
+            let month = (month_idx % 12) as u32 + 1;
+            days_from_civil(year, month, day_of_month as u32) * NANOS_PER_DAY
+        }
+    }
+}
+
+/// Convert a number of days since January 1, 1970 into a (year, month, day) date in the Gregorian calendar.
+/// Algorithm from http://howardhinnant.github.io/date_algorithms.html
+fn civil_from_days(days: u64) -> (i64, u32, u32) {
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+    let year = year_of_era + era * 400 + (month <= 2) as i64;
+
+    (year, month, day)
+}
+
+/// Convert a (year, month, day) date in the Gregorian calendar into the number of days since January 1, 1970.
+/// Algorithm from http://howardhinnant.github.io/date_algorithms.html
+fn days_from_civil(year: i64, month: u32, day: u32) -> u64 {
+    let year = year - (month <= 2) as i64;
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = month as i64;
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    (era * 146_097 + day_of_era - 719_468) as u64
+}
@@ -22,38 +22,28 @@ impl Keypom {
             "Must provide between 1 and 100 keys at a time"
         );
 
-        // If there is a public sale and the predecessor isn't the funder, perform checks and return revenue
-        if let Some(allowlist) = drop
+        // Accounts with a spending allowance from the funder add keys on the funder's behalf
+        let spending_allowance =
+            caller_id != funder_id && self.has_spending_allowance(&funder_id, &caller_id);
+
+        // If there is a public sale, anyone on the allowlist can add keys. Otherwise the caller needs the key adder role
+        let on_allowlist = drop
             .config
             .as_ref()
             .and_then(|c| c.add_key_allowlist.as_ref())
-        {
-            if funder_id != caller_id {
-                require!(allowlist.contains(&caller_id), "caller not on allowlist");
-            }
-        } else {
-            require!(
-                funder_id == caller_id,
-                "Only funder can add keys to the drop"
-            );
+            .map(|allowlist| allowlist.contains(&caller_id))
+            .unwrap_or(false);
+        if !spending_allowance && !on_allowlist {
+            assert_drop_permission(&drop, &caller_id, DropOperation::add_keys);
         }
 
         // Parse the external assets and store them in the contract
         let max_key_uses = drop.max_key_uses;
 
-        let mut total_cost_per_key = 0;
-        let mut total_allowance_per_key = drop
-            .config
-            .as_ref()
-            .and_then(|config| config.extra_allowance_per_key)
-            .unwrap_or(U128(0))
-            .0;
-
         // Get the total cost and allowance required for a key that has all its uses remaining
         // We'll then multiply this by the number of keys we want to add and charge the user
-        get_total_costs_for_key(
-            &mut total_cost_per_key,
-            &mut total_allowance_per_key,
+        let (total_cost_per_key, total_allowance_per_key) = get_costs_per_new_key(
+            &drop.config,
             max_key_uses,
             &drop.asset_by_id,
             &drop.asset_data_for_uses,
@@ -71,20 +61,40 @@ impl Keypom {
             &key_data,
             total_allowance_per_key,
         );
+        drop.update_tokens_required(key_data.len() as u128, 0, max_key_uses);
 
         // Write the updated drop data to storage
         self.drop_by_id.insert(&drop_id, &drop);
 
         // Measure final costs
         let net_storage = env::storage_usage() - initial_storage;
-        self.determine_costs(
+        let payer_id = if spending_allowance {
+            &funder_id
+        } else {
+            &caller_id
+        };
+        let payer_balance = self.get_user_balance(payer_id.clone()).0;
+        let costs = self.get_drop_costs(
+            &self.get_fees_for_user(payer_id),
             key_data.len(),
             false, // No drop was created
             total_cost_per_key,
             total_allowance_per_key,
             net_storage,
-            env::attached_deposit().as_yoctonear(),
         );
+        self.determine_costs(payer_id, costs, env::attached_deposit().as_yoctonear());
+
+        // Whatever was drawn from the funder's balance counts towards the allowance
+        if spending_allowance {
+            let amount_spent =
+                payer_balance.saturating_sub(self.get_user_balance(funder_id.clone()).0);
+            event_logs.push(self.internal_spend_allowance(
+                &funder_id,
+                &caller_id,
+                amount_spent,
+                &drop_id,
+            ));
+        }
 
         // Now that everything is done (no more potential for panics), we can log the events
         log_events(event_logs);
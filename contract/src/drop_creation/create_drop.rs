@@ -1,44 +1,101 @@
+use std::collections::HashSet;
+
 use crate::*;
 
 #[near_bindgen]
 impl Keypom {
+    /// Create multiple drops at once, each with their own keys, assets and config. Any drop ID that is `None` is
+    /// Assigned by Keypom from the funder's drop counter. Every drop is validated before anything is created and each
+    /// Drop's costs come out of whatever is left of the attached deposit before falling back to the funder's balance.
+    /// Returns the ID and a breakdown of the costs for every drop in the same order they were passed in
     #[payable]
     pub fn create_drop_batch(
         &mut self,
-        drop_ids: Vec<DropId>,
+        drop_ids: Vec<Option<DropId>>,
+        key_datas: Option<Vec<Vec<ExtKeyData>>>,
         asset_datas: Vec<Vec<ExtAssetDataForUses>>,
         drop_configs: Vec<Option<DropConfig>>,
         change_user_metadata: Option<String>,
 
         on_success: Option<OnSuccessCallData>,
-    ) -> bool {
-        // Check if all vectors are of the same length
+    ) -> Vec<ExtDropCreationResult> {
+        self.assert_no_global_freeze();
+
+        // Validate everything up front so that nothing is created if any part of the batch is invalid
+        let num_drops = drop_ids.len();
+        let key_datas = key_datas.unwrap_or_else(|| vec![vec![]; num_drops]);
         require!(
-            drop_ids.len() == asset_datas.len() && drop_ids.len() == drop_configs.len(),
+            num_drops == key_datas.len()
+                && num_drops == asset_datas.len()
+                && num_drops == drop_configs.len(),
             "All vectors must be of the same length"
         );
+        require!(
+            num_drops > 0 && num_drops <= 50,
+            "Must create between 1 and 50 drops at a time"
+        );
+        let mut unique_drop_ids = HashSet::new();
+        for drop_id in drop_ids.iter().flatten() {
+            self.assert_valid_new_drop_id(drop_id);
+            require!(
+                unique_drop_ids.insert(drop_id),
+                format!("Drop ID {} is used more than once in the batch", drop_id)
+            );
+        }
+        for key_data in key_datas.iter() {
+            require!(
+                key_data.len() <= 100,
+                "Cannot add more than 100 keys at a time"
+            );
+        }
+        if let Some(success_data) = on_success.as_ref() {
+            // Check if the method is prohibited
+            require!(
+                !DEFAULT_PROHIBITED_FC_METHODS.contains(&success_data.method_name.as_str()),
+                format!(
+                    "Method {} is prohibited from being called in an FC drop",
+                    success_data.method_name
+                )
+            );
+
+            // Check if the receiver is valid
+            require!(
+                success_data.receiver_id != env::current_account_id(),
+                "Receiver ID cannot be current Keypom contract."
+            );
+        }
 
         // Start with the full attached deposit
         let mut deposit_left = env::attached_deposit().as_yoctonear();
         near_sdk::log!("Initial deposit: {}", deposit_left);
 
-        // Iterate over the inputs and call `create_drop` for each set of elements
-        for (i, drop_id) in drop_ids.into_iter().enumerate() {
-            let current_asset_data = asset_datas[i].clone(); // Assuming `create_drop` can take Vec<ExtAssetDataForUses>
-            let current_drop_config = drop_configs[i].clone();
-
+        // Iterate over the inputs and create each drop
+        let mut results = Vec::with_capacity(num_drops);
+        let inputs = drop_ids
+            .into_iter()
+            .zip(key_datas)
+            .zip(asset_datas)
+            .zip(drop_configs);
+        for (i, (((drop_id, key_data), asset_data), drop_config)) in inputs.enumerate() {
             // Set the new deposit for the next iteration
-            deposit_left = self.internal_create_drop(
+            let (drop_id, new_deposit_left, costs) = self.internal_create_drop(
                 drop_id,
-                vec![],
-                current_asset_data,
-                current_drop_config,
+                key_data,
+                asset_data,
+                drop_config,
+                env::predecessor_account_id(),
                 deposit_left,
             );
+            deposit_left = new_deposit_left;
             near_sdk::log!("Deposit left after drop {}: {}", i, deposit_left);
+
+            results.push(ExtDropCreationResult {
+                drop_id,
+                costs: costs.to_external(),
+            });
         }
 
-        // TODO: implement appending metadata to user's metadata. Ensure result is still JSON stringified
+        // Merge the new metadata into the user's existing metadata
         if let Some(metadata) = change_user_metadata {
             deposit_left = self.internal_modify_user_metadata(Some(metadata), deposit_left, None);
             near_sdk::log!(
@@ -55,21 +112,6 @@ impl Keypom {
                     // Decrement the attached deposit by the amount used in the call
                     deposit_left -= success_data.attached_deposit.0;
 
-                    // Check if the method is prohibited
-                    require!(
-                        !DEFAULT_PROHIBITED_FC_METHODS.contains(&success_data.method_name.as_str()),
-                        format!(
-                            "Method {} is prohibited from being called in an FC drop",
-                            success_data.method_name
-                        )
-                    );
-
-                    // Check if the receiver is valid
-                    require!(
-                        success_data.receiver_id != env::current_account_id(),
-                        "Receiver ID cannot be current Keypom contract."
-                    );
-
                     // Fire the cross-contract call
                     Promise::new(success_data.receiver_id).function_call_weight(
                         success_data.method_name.clone(),
@@ -85,27 +127,33 @@ impl Keypom {
             let predecessor = env::predecessor_account_id();
             near_sdk::log!("Refunding {} excess deposit", deposit_left);
             Promise::new(predecessor).transfer(NearToken::from_yoctonear(deposit_left));
-            return true;
         }
 
-        true
+        results
     }
+
+    /// Create a new drop and return its ID. If *drop_id* isn't passed in, one is assigned by Keypom from the funder's
+    /// Drop counter. If *funder_id* is passed in, the drop is created on behalf of that funder using
+    /// The spending allowance they granted the predecessor. Any costs not covered by the attached deposit
+    /// Are drawn from the funder's balance and the funder is recorded as the drop's funder.
     #[payable]
     pub fn create_drop(
         &mut self,
-        drop_id: DropId,
+        drop_id: Option<DropId>,
         key_data: Vec<ExtKeyData>,
         asset_data: Vec<ExtAssetDataForUses>,
 
         drop_config: Option<DropConfig>,
-    ) -> bool {
+        funder_id: Option<AccountId>,
+    ) -> DropId {
         self.assert_no_global_freeze();
         // Get the amount of $NEAR that should be refunded out of the user's attached deposit
-        let refund_amount = self.internal_create_drop(
+        let (drop_id, refund_amount, _) = self.internal_create_drop(
             drop_id,
             key_data,
             asset_data,
             drop_config,
+            funder_id.unwrap_or_else(env::predecessor_account_id),
             env::attached_deposit().as_yoctonear(),
         );
 
@@ -115,32 +163,37 @@ impl Keypom {
 
             near_sdk::log!("Refunding {} excess deposit", refund_amount);
             Promise::new(predecessor).transfer(NearToken::from_yoctonear(refund_amount));
-            return true;
         }
 
-        true
+        drop_id
     }
 
+    /// Create a drop owned by *funder_id* and return its ID, how much of the attached deposit should be refunded
+    /// And a breakdown of the costs. If no drop ID is passed in, one is assigned from the funder's drop counter
     pub(crate) fn internal_create_drop(
         &mut self,
-        drop_id: DropId,
+        drop_id: Option<DropId>,
         key_data: Vec<ExtKeyData>,
         asset_data: Vec<ExtAssetDataForUses>,
 
         drop_config: Option<DropConfig>,
+        funder_id: AccountId,
         attached_deposit: Balance,
-    ) -> Balance {
+    ) -> (DropId, Balance, DropCosts) {
         self.assert_no_global_freeze();
-        require!(!drop_id.contains(':'), "Drop ID cannot contain a colon (:)");
-        require!(
-            self.drop_by_id.get(&drop_id).is_none(),
-            format!("Drop with ID {} already exists", drop_id)
-        );
 
         // Before anything, measure storage usage so we can net the cost and charge the funder
         let initial_storage = env::storage_usage();
         near_sdk::log!("initial bytes {}", initial_storage);
 
+        let drop_id = match drop_id {
+            Some(drop_id) => {
+                self.assert_valid_new_drop_id(&drop_id);
+                drop_id
+            }
+            None => self.internal_next_drop_id(&funder_id),
+        };
+
         // Instantiate the drop data structures
         let mut key_info_by_token_id: UnorderedMap<TokenId, InternalKeyInfo> =
             UnorderedMap::new(StorageKeys::KeyInfoByPk {
@@ -151,41 +204,46 @@ impl Keypom {
             UnorderedMap::new(StorageKeys::AssetById {
                 drop_id_hash: hash_string(&drop_id.to_string()),
             });
-        let mut asset_data_for_uses = vec![];
 
         require!(
             key_data.len() <= 100,
             "Cannot add more than 100 keys at a time"
         );
 
-        let mut max_key_uses = 0;
         // Parse the external asset data and convert it into the internal representation
-        for ext_asset_data in asset_data {
-            // Convert the external asset data into the internal asset data
-            asset_data_for_uses.push(InternalAssetDataForUses::from(&ext_asset_data));
-
-            // Take the assets and populate the asset_by_id mapping
-            store_assets_by_id(&ext_asset_data.assets, &mut asset_by_id);
+        let (asset_data_for_uses, max_key_uses) =
+            store_asset_data_for_uses(&asset_data, &mut asset_by_id);
 
-            max_key_uses += ext_asset_data.uses;
+        // Make sure the NFT keys can always be resold without the payout failing
+        if let Some(nft_keys_config) = drop_config
+            .as_ref()
+            .and_then(|c| c.nft_keys_config.as_ref())
+        {
+            assert_valid_nft_keys_config(nft_keys_config, &funder_id);
+        }
+        if let Some(crowdfund) = drop_config.as_ref().and_then(|c| c.crowdfund.as_ref()) {
+            assert_valid_crowdfund_config(crowdfund, &asset_by_id);
         }
 
-        let mut total_cost_per_key = 0;
-        let mut total_allowance_per_key = drop_config
-            .as_ref()
-            .and_then(|config| config.extra_allowance_per_key)
-            .unwrap_or(U128(0))
-            .0;
         // Get the total cost and allowance required for a key that has all its uses remaining
         // We'll then multiply this by the number of keys we want to add and charge the user
-        get_total_costs_for_key(
-            &mut total_cost_per_key,
-            &mut total_allowance_per_key,
+        let (total_cost_per_key, total_allowance_per_key) = get_costs_per_new_key(
+            &drop_config,
             max_key_uses,
             &asset_by_id,
             &asset_data_for_uses,
         );
 
+        // Everything the drop's claims can pay forward is reserved up front
+        let pay_it_forward_reserve = self.get_pay_it_forward_reserve(
+            Some(&drop_id),
+            Some(&funder_id),
+            &asset_data_for_uses,
+            total_cost_per_key
+                + total_allowance_per_key
+                + self.get_fees_for_user(&funder_id).per_key,
+        );
+
         // Keep track of all the key IDs
         let mut next_key_id = 0;
         // Keep track of all the events
@@ -202,16 +260,34 @@ impl Keypom {
         );
 
         // Write the drop data to storage
-        let funder_id = env::predecessor_account_id();
-        let drop = InternalDrop {
+        let mut drop = InternalDrop {
             max_key_uses,
             asset_data_for_uses,
+            tokens_required_by_asset: HashMap::new(),
             asset_by_id,
             key_info_by_token_id,
             next_key_id,
             config: drop_config,
             funder_id: funder_id.clone(),
+            referrals_by_account: UnorderedMap::new(StorageKeys::ReferralsByAccount {
+                drop_id_hash: hash_string(&drop_id.to_string()),
+            }),
+            pay_it_forward_keys_minted: 0,
+            pay_it_forward_reserve,
+            pending_transfer: None,
+            ft_deposits_by_sender: UnorderedMap::new(StorageKeys::FTDepositsBySender {
+                drop_id_hash: hash_string(&drop_id.to_string()),
+            }),
+            ft_contributed_by_asset: HashMap::new(),
+            crowdfund_raised: 0,
+            crowdfund_collected: false,
+            near_contributions_by_sender: UnorderedMap::new(
+                StorageKeys::NearContributionsBySender {
+                    drop_id_hash: hash_string(&drop_id.to_string()),
+                },
+            ),
         };
+        drop.update_tokens_required(key_data.len() as u128, 0, max_key_uses);
         require!(
             self.drop_by_id.insert(&drop_id, &drop).is_none(),
             format!("Drop with ID {} already exists", drop_id)
@@ -221,14 +297,32 @@ impl Keypom {
 
         // Measure final costs
         let net_storage = env::storage_usage() - initial_storage;
-        let refund_amount = self.determine_costs(
+        let funder_balance = self.get_user_balance(funder_id.clone()).0;
+        let costs = self.get_drop_costs(
+            &self.get_fees_for_user(&funder_id),
             key_data.len(),
             true, // We did create a drop here
             total_cost_per_key,
             total_allowance_per_key,
             net_storage,
-            attached_deposit,
         );
+        let (refund_amount, mut costs) = self.determine_costs(&funder_id, costs, attached_deposit);
+        let refund_amount =
+            self.charge_with_deposit_or_balance(&funder_id, pay_it_forward_reserve, refund_amount);
+        costs.pay_it_forward = pay_it_forward_reserve;
+
+        // If the drop was created on behalf of the funder, whatever was drawn from their balance counts towards the allowance
+        let spender_id = env::predecessor_account_id();
+        if spender_id != funder_id {
+            let amount_spent =
+                funder_balance.saturating_sub(self.get_user_balance(funder_id.clone()).0);
+            event_logs.push(self.internal_spend_allowance(
+                &funder_id,
+                &spender_id,
+                amount_spent,
+                &drop_id,
+            ));
+        }
 
         // Construct the drop creation log and push it to the event logs
         let drop_creation_event: EventLog = EventLog {
@@ -236,7 +330,7 @@ impl Keypom {
             version: KEYPOM_STANDARD_VERSION.to_string(),
             event: EventLogVariant::DropCreation(CreateOrDeleteDropLog {
                 funder_id: funder_id.to_string(),
-                drop_id,
+                drop_id: drop_id.clone(),
             }),
         };
         event_logs.push(drop_creation_event);
@@ -246,6 +340,6 @@ impl Keypom {
 
         // Now that everything is done (no more potential for panics), we can log the events
         log_events(event_logs);
-        refund_amount
+        (drop_id, refund_amount, costs)
     }
 }
@@ -0,0 +1,149 @@
+use crate::*;
+
+#[near_bindgen]
+impl Keypom {
+    /// Create a new drop with the same assets, use configs and drop config as one of the caller's existing drops.
+    /// Only the asset definitions are copied, not any FT balances or NFT token IDs. The new drop is owned by the caller
+    /// And costs are charged the same way as `create_drop`. Returns the ID of the new drop.
+    #[payable]
+    pub fn clone_drop(
+        &mut self,
+        source_drop_id: DropId,
+        new_drop_id: Option<DropId>,
+        overrides: Option<DropCloneOverrides>,
+    ) -> DropId {
+        self.assert_no_global_freeze();
+
+        let template = self.internal_get_drop_shape(&source_drop_id);
+        self.internal_create_drop_from_template(new_drop_id, template, overrides)
+    }
+
+    /// Save the shape of one of the caller's existing drops as a named template that new drops can be created from.
+    /// Saving a template with a name that already exists replaces it.
+    /// Any storage is paid for by the funder with their attached deposit or balance.
+    #[payable]
+    pub fn save_drop_template(&mut self, template_name: String, source_drop_id: DropId) -> bool {
+        self.assert_no_global_freeze();
+        let initial_storage = env::storage_usage();
+        let funder_id = env::predecessor_account_id();
+
+        let template = self.internal_get_drop_shape(&source_drop_id);
+        let mut templates = self
+            .drop_templates_by_funder
+            .get(&funder_id)
+            .unwrap_or_else(|| {
+                UnorderedMap::new(StorageKeys::DropTemplatesByFunderInner {
+                    account_id_hash: hash_string(&funder_id.to_string()),
+                })
+            });
+        templates.insert(&template_name, &template);
+        self.drop_templates_by_funder.insert(&funder_id, &templates);
+
+        let refund_amount =
+            self.internal_settle_storage(initial_storage, env::attached_deposit().as_yoctonear());
+        if refund_amount > 0 {
+            Promise::new(funder_id).transfer(NearToken::from_yoctonear(refund_amount));
+        }
+
+        true
+    }
+
+    /// Delete one of the caller's saved drop templates. Any storage released is refunded to the funder.
+    pub fn delete_drop_template(&mut self, template_name: String) -> bool {
+        let initial_storage = env::storage_usage();
+        let funder_id = env::predecessor_account_id();
+
+        let mut templates = self
+            .drop_templates_by_funder
+            .get(&funder_id)
+            .expect("No templates found for funder");
+        require!(
+            templates.remove(&template_name).is_some(),
+            "Template not found"
+        );
+        if templates.is_empty() {
+            self.drop_templates_by_funder.remove(&funder_id);
+        } else {
+            self.drop_templates_by_funder.insert(&funder_id, &templates);
+        }
+
+        let refund_amount = self.internal_settle_storage(initial_storage, 0);
+        if refund_amount > 0 {
+            Promise::new(funder_id).transfer(NearToken::from_yoctonear(refund_amount));
+        }
+
+        true
+    }
+
+    /// Create a new drop from one of the caller's saved templates and return its ID.
+    /// Costs are charged the same way as `create_drop`.
+    #[payable]
+    pub fn create_drop_from_template(
+        &mut self,
+        template_name: String,
+        new_drop_id: Option<DropId>,
+        overrides: Option<DropCloneOverrides>,
+    ) -> DropId {
+        self.assert_no_global_freeze();
+
+        let template = self
+            .drop_templates_by_funder
+            .get(&env::predecessor_account_id())
+            .and_then(|templates| templates.get(&template_name))
+            .expect("Template not found");
+        self.internal_create_drop_from_template(new_drop_id, template, overrides)
+    }
+
+    /// Get the assets, use configs and drop config of a drop that the predecessor funded
+    fn internal_get_drop_shape(&self, drop_id: &DropId) -> DropTemplate {
+        let drop = self.drop_by_id.get(drop_id).expect("No drop found");
+        require!(
+            drop.funder_id == env::predecessor_account_id(),
+            "Only drop funder can copy the drop"
+        );
+
+        DropTemplate {
+            asset_data: drop.get_ext_asset_data(),
+            config: drop.config,
+        }
+    }
+
+    /// Apply any overrides to the template and create the new drop, refunding any excess deposit.
+    /// If no drop ID is passed in, one is assigned from the funder's drop counter
+    fn internal_create_drop_from_template(
+        &mut self,
+        new_drop_id: Option<DropId>,
+        template: DropTemplate,
+        overrides: Option<DropCloneOverrides>,
+    ) -> DropId {
+        let overrides = overrides.unwrap_or(DropCloneOverrides {
+            key_data: None,
+            drop_config: None,
+            metadata: None,
+        });
+
+        let mut drop_config = overrides.drop_config.or(template.config);
+        if let Some(metadata) = overrides.metadata {
+            let mut config = drop_config.unwrap_or_default();
+            config.metadata = Some(metadata);
+            drop_config = Some(config);
+        }
+
+        let (drop_id, refund_amount, _) = self.internal_create_drop(
+            new_drop_id,
+            overrides.key_data.unwrap_or_default(),
+            template.asset_data,
+            drop_config,
+            env::predecessor_account_id(),
+            env::attached_deposit().as_yoctonear(),
+        );
+
+        if refund_amount > 0 {
+            near_sdk::log!("Refunding {} excess deposit", refund_amount);
+            Promise::new(env::predecessor_account_id())
+                .transfer(NearToken::from_yoctonear(refund_amount));
+        }
+
+        drop_id
+    }
+}
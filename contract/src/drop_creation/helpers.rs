@@ -32,6 +32,8 @@ impl Keypom {
                 password_by_use,
                 metadata,
                 key_owner,
+                token_metadata,
+                time_config,
             } = data;
 
             let token_id = format!("{}:{}", drop_id, next_key_id);
@@ -41,15 +43,12 @@ impl Keypom {
             );
 
             // Iterate through the key_data.password_by_use hash map (if there is one) and decode all the strings to hex
-            let pw_by_use: Option<HashMap<UseNumber, Vec<u8>>> =
-                password_by_use.as_ref().map(|p| {
-                    p.into_iter()
-                        .map(|(k, v)| {
-                            let decoded = hex::decode(v).expect("Invalid hex string");
-                            (*k, decoded)
-                        })
-                        .collect()
-                });
+            let pw_by_use = decode_password_by_use(password_by_use);
+
+            // Make sure the key's own time config is valid
+            if let Some(time_config) = time_config {
+                assert_valid_time_config(time_config);
+            }
 
             if let Some(owner) = key_owner {
                 // Add the NFT key to the owner's list of tokens
@@ -60,11 +59,16 @@ impl Keypom {
                 &InternalKeyInfo {
                     pub_key: public_key.clone(),
                     remaining_uses: max_uses_per_key,
+                    uses_consumed: 0,
                     owner_id: key_owner.clone(),
                     next_approval_id: 0,
                     last_claimed: 0, // Set to 0 since this will make the key always claimable.
+                    last_transferred: 0,
                     approved_account_ids: Default::default(),
+                    approval_expiries: Default::default(),
                     metadata: metadata.clone(),
+                    token_metadata: token_metadata.clone(),
+                    time_config: time_config.clone(),
                     pw_by_use,
                 },
             );
@@ -111,40 +115,58 @@ impl Keypom {
         env::promise_return(promise);
     }
 
-    /// Tally up all the costs for adding keys / creating a drop and refund any excess deposit
-    pub(crate) fn determine_costs(
-        &mut self,
+    /// Get the fees that an account is charged. This is their custom fee structure if one was set by the owner
+    pub(crate) fn get_fees_for_user(&self, account_id: &AccountId) -> KeypomFees {
+        self.fees_per_user
+            .get(account_id)
+            .unwrap_or(self.fee_structure.clone())
+    }
+
+    /// Tally up all the costs for adding keys / creating a drop given the fee structure to charge with
+    pub(crate) fn get_drop_costs(
+        &self,
+        fees_for_user: &KeypomFees,
         num_keys: usize,
         did_create_drop: bool,
         asset_cost_per_key: Balance,
         allowance_per_key: Balance,
         net_storage: u64,
-        attached_deposit: Balance,
-    ) -> Balance {
+    ) -> DropCosts {
         let num_keys = num_keys as u128;
 
-        let storage_cost = net_storage as Balance * env::storage_byte_cost().as_yoctonear();
-        let total_asset_cost = asset_cost_per_key * num_keys;
-        let total_allowance_cost = allowance_per_key * num_keys;
+        DropCosts {
+            storage: net_storage as Balance * env::storage_byte_cost().as_yoctonear(),
+            assets: asset_cost_per_key * num_keys,
+            allowance: allowance_per_key * num_keys,
+            fees: num_keys * fees_for_user.per_key
+                + did_create_drop as u128 * fees_for_user.per_drop,
+            pay_it_forward: 0,
+        }
+    }
 
-        let fees_for_user = self
-            .fees_per_user
-            .get(&env::predecessor_account_id())
-            .unwrap_or(self.fee_structure.clone());
-        let total_fees =
-            num_keys * fees_for_user.per_key + did_create_drop as u128 * fees_for_user.per_drop;
-        self.fees_collected += total_fees;
-        let total_cost = total_asset_cost + storage_cost + total_allowance_cost + total_fees;
+    /// Charge the costs for adding keys / creating a drop and refund any excess deposit.
+    /// The `funder_id` is charged for whatever the attached deposit doesn't cover.
+    /// Returns the amount to refund along with the costs that were charged
+    pub(crate) fn determine_costs(
+        &mut self,
+        funder_id: &AccountId,
+        costs: DropCosts,
+        attached_deposit: Balance,
+    ) -> (Balance, DropCosts) {
+        self.fees_collected += costs.fees;
+        let total_cost = costs.total();
 
         near_sdk::log!(
             "total {} storage {} asset {} allowance {} keypom fees {}",
             total_cost,
-            storage_cost,
-            total_asset_cost,
-            total_allowance_cost,
-            total_fees
+            costs.storage,
+            costs.assets,
+            costs.allowance,
+            costs.fees
         );
-        self.charge_with_deposit_or_balance(total_cost, attached_deposit)
+        let refund_amount =
+            self.charge_with_deposit_or_balance(funder_id, total_cost, attached_deposit);
+        (refund_amount, costs)
     }
 
     /// Internal method to add a drop ID the list of drops a funder has. If they don't have any, instantiate
@@ -165,6 +187,30 @@ impl Keypom {
         self.drop_ids_by_funder.insert(funder_id, &drop_set);
     }
 
+    /// Assert that a drop ID passed in by the funder is valid and isn't already taken
+    pub(crate) fn assert_valid_new_drop_id(&self, drop_id: &DropId) {
+        require!(!drop_id.contains(':'), "Drop ID cannot contain a colon (:)");
+        require!(
+            !self.drop_by_id.contains_key(drop_id),
+            format!("Drop with ID {} already exists", drop_id)
+        );
+    }
+
+    /// Assign the next unused drop ID for the funder from their drop counter. IDs that were already
+    /// Taken (i.e by a drop created with a custom ID) are skipped
+    pub(crate) fn internal_next_drop_id(&mut self, funder_id: &AccountId) -> DropId {
+        let mut nonce = self.next_drop_nonce_by_funder.get(funder_id).unwrap_or(0);
+        let mut drop_id = format!("{}-{}", funder_id, nonce);
+        while self.drop_by_id.contains_key(&drop_id) {
+            nonce += 1;
+            drop_id = format!("{}-{}", funder_id, nonce);
+        }
+
+        self.next_drop_nonce_by_funder
+            .insert(funder_id, &(nonce + 1));
+        drop_id
+    }
+
     /// Internal method to remove a drop ID from the list of drops a funder has.
     /// If the funder has no more drops, we remove the funder from the drop_ids_by_funder collection
     pub(crate) fn internal_remove_drop_for_funder(
@@ -190,11 +236,90 @@ impl Keypom {
     }
 }
 
+/// Asset definitions for a drop mapped by asset ID. Costs are calculated the same way whether the
+/// Assets are in the contract's storage or only in memory (i.e when quoting the costs of a drop)
+pub trait AssetStore {
+    fn get_asset(&self, asset_id: &AssetId) -> Option<InternalAsset>;
+    fn insert_asset(&mut self, asset_id: &AssetId, asset: &InternalAsset);
+}
+
+impl AssetStore for UnorderedMap<AssetId, InternalAsset> {
+    fn get_asset(&self, asset_id: &AssetId) -> Option<InternalAsset> {
+        self.get(asset_id)
+    }
+
+    fn insert_asset(&mut self, asset_id: &AssetId, asset: &InternalAsset) {
+        self.insert(asset_id, asset);
+    }
+}
+
+impl AssetStore for HashMap<AssetId, InternalAsset> {
+    fn get_asset(&self, asset_id: &AssetId) -> Option<InternalAsset> {
+        self.get(asset_id).cloned()
+    }
+
+    fn insert_asset(&mut self, asset_id: &AssetId, asset: &InternalAsset) {
+        self.insert(asset_id.clone(), asset.clone());
+    }
+}
+
+/// Convert the external asset data for a drop into the internal representation and store its assets in the asset by ID map.
+/// Returns the internal asset data along with the maximum number of uses a key in the drop can have
+pub(crate) fn store_asset_data_for_uses(
+    asset_data: &Vec<ExtAssetDataForUses>,
+    asset_by_id: &mut impl AssetStore,
+) -> (Vec<InternalAssetDataForUses>, UseNumber) {
+    let mut asset_data_for_uses = vec![];
+    let mut max_key_uses = 0;
+    for ext_asset_data in asset_data {
+        // Convert the external asset data into the internal asset data
+        asset_data_for_uses.push(InternalAssetDataForUses::from(ext_asset_data));
+
+        // Take the assets and populate the asset_by_id mapping
+        store_assets_by_id(&ext_asset_data.assets, asset_by_id);
+        store_referral_asset(&ext_asset_data.config, asset_by_id);
+
+        max_key_uses += ext_asset_data.uses;
+    }
+
+    (asset_data_for_uses, max_key_uses)
+}
+
+/// Get the total cost and allowance required for a new key in a drop (one that has all its uses remaining)
+pub(crate) fn get_costs_per_new_key(
+    drop_config: &Option<DropConfig>,
+    max_key_uses: UseNumber,
+    asset_by_id: &impl AssetStore,
+    asset_data_for_uses: &Vec<InternalAssetDataForUses>,
+) -> (Balance, Balance) {
+    let mut total_cost_per_key = 0;
+    let mut total_allowance_per_key = drop_config
+        .as_ref()
+        .and_then(|config| config.extra_allowance_per_key)
+        .unwrap_or(U128(0))
+        .0;
+
+    // Time configs are only checked for new keys since their start has to be in the future. Existing
+    // Keys go through `get_total_costs_for_key` after the drop has started
+    for asset_data in asset_data_for_uses {
+        if let Some(time_config) = asset_data.config.as_ref().and_then(|c| c.time.as_ref()) {
+            assert_valid_time_config(time_config)
+        }
+    }
+
+    get_total_costs_for_key(
+        &mut total_cost_per_key,
+        &mut total_allowance_per_key,
+        max_key_uses,
+        asset_by_id,
+        asset_data_for_uses,
+    );
+
+    (total_cost_per_key, total_allowance_per_key)
+}
+
 /// Helper function to ingest external assets and store them in the internal asset by ID map
-pub fn store_assets_by_id(
-    ext_assets: &Vec<Option<ExtAsset>>,
-    asset_by_id: &mut UnorderedMap<AssetId, InternalAsset>,
-) {
+pub fn store_assets_by_id(ext_assets: &Vec<Option<ExtAsset>>, asset_by_id: &mut impl AssetStore) {
     let mut fc_idx = 0;
     for ext_asset in ext_assets {
         // If the external asset is of type FCData, the asset ID will be the incrementing number
@@ -211,10 +336,22 @@ pub fn store_assets_by_id(
 
         // Only insert into the asset ID map if it doesn't already exist
         // If we insert, we should also add the cost to the total asset cost
-        if asset_by_id.get(&asset_id).is_none() {
+        if asset_by_id.get_asset(&asset_id).is_none() {
             let internal_asset = ext_asset_to_internal(ext_asset.as_ref());
 
-            asset_by_id.insert(&asset_id, &internal_asset);
+            asset_by_id.insert_asset(&asset_id, &internal_asset);
+        }
+    }
+}
+
+/// Helper function to store the referral reward asset (if any) for a set of uses in the internal asset by ID map
+pub fn store_referral_asset(use_config: &Option<UseConfig>, asset_by_id: &mut impl AssetStore) {
+    if let Some(referral) = use_config.as_ref().and_then(|c| c.referral.as_ref()) {
+        let asset_id = get_referral_asset_id(&referral.reward);
+
+        // Only insert into the asset ID map if it doesn't already exist
+        if asset_by_id.get_asset(&asset_id).is_none() {
+            asset_by_id.insert_asset(&asset_id, &referral.reward.to_internal_asset());
         }
     }
 }
@@ -244,4 +381,87 @@ pub(crate) fn assert_valid_time_config(config: &TimeConfig) {
             "The start timestamp must be less than the end timestamp"
         );
     }
+
+    if let Some(schedule) = config.schedule.as_ref() {
+        assert_valid_recurring_schedule(config, schedule);
+    }
+}
+
+/// Ensure that a recurring schedule has a start to be anchored to and that its period and window make sense
+pub(crate) fn assert_valid_recurring_schedule(config: &TimeConfig, schedule: &RecurringSchedule) {
+    require!(
+        config.start.is_some(),
+        "If you want to set a recurring schedule, you must also set a start timestamp"
+    );
+    require!(
+        config.interval.is_none(),
+        "A recurring schedule cannot be used alongside a claim interval"
+    );
+
+    let min_period_length = match schedule.period {
+        SchedulePeriod::monthly { day_of_month } => {
+            require!(
+                (1..=28).contains(&day_of_month),
+                "The day of the month must be between 1 and 28"
+            );
+            28 * NANOS_PER_DAY
+        }
+        SchedulePeriod::weekly { day_of_week } => {
+            require!(
+                day_of_week <= 6,
+                "The day of the week must be between 0 (Monday) and 6 (Sunday)"
+            );
+            7 * NANOS_PER_DAY
+        }
+    };
+
+    if let Some(claim_window) = schedule.claim_window {
+        require!(
+            claim_window > 0 && claim_window <= min_period_length,
+            "The claim window must be greater than 0 and no longer than a period"
+        );
+    }
+}
+
+/// Ensure that the account name rules passed in are valid
+pub(crate) fn assert_valid_account_name_rules(rules: &AccountNameRules) {
+    if let (Some(min), Some(max)) = (rules.min_length, rules.max_length) {
+        require!(
+            min <= max,
+            "The minimum account name length must be less than or equal to the maximum"
+        );
+    }
+
+    // The prefix and suffix alone must be able to fit within the maximum length
+    let fixed_length = rules.prefix.as_ref().map(|p| p.len()).unwrap_or(0)
+        + rules.suffix.as_ref().map(|s| s.len()).unwrap_or(0);
+    require!(
+        fixed_length as u32 <= rules.max_length.unwrap_or(u32::MAX),
+        "The account name prefix and suffix cannot be longer than the maximum length"
+    );
+}
+
+/// Ensure that the crowdfund config passed in is valid and that any FTs being raised are part of the drop
+pub(crate) fn assert_valid_crowdfund_config(
+    config: &CrowdfundConfig,
+    asset_by_id: &UnorderedMap<AssetId, InternalAsset>,
+) {
+    require!(
+        config.target.0 > 0,
+        "Crowdfund target must be greater than 0"
+    );
+    require!(
+        config.deadline > env::block_timestamp(),
+        "Crowdfund deadline must be in the future"
+    );
+
+    if let Some(ft_contract_id) = config.ft_contract_id.as_ref() {
+        require!(
+            matches!(
+                asset_by_id.get(&ft_contract_id.to_string()),
+                Some(InternalAsset::ft(_))
+            ),
+            "Crowdfunded FTs must be one of the drop's assets"
+        );
+    }
 }
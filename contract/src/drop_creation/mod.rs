@@ -1,5 +1,7 @@
 pub mod create_drop;
+pub mod drop_templates;
 pub mod add_keys;
 pub mod helpers;
+pub mod pay_it_forward;
 
 pub use helpers::*;
\ No newline at end of file
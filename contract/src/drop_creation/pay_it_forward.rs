@@ -0,0 +1,220 @@
+use crate::*;
+
+#[near_bindgen]
+impl Keypom {
+    /// Reserve pay it forward keys for the claimer as part of a claim. The keys count towards the source drop's budget
+    /// And their costs are taken out of what the funder reserved when the drop was created. Nothing is minted until
+    /// `internal_mint_pay_it_forward_keys` is called with the reservation. Returns None if no keys were requested.
+    /// Panics (reverting the claim) if the use doesn't allow it or the claim or drop budget would be exceeded.
+    pub(crate) fn internal_reserve_pay_it_forward_keys(
+        &mut self,
+        source_drop_id: &DropId,
+        pay_it_forward: Option<&PayItForwardConfig>,
+        public_keys: Vec<PublicKey>,
+    ) -> Option<PayItForwardReservation> {
+        if public_keys.is_empty() {
+            return None;
+        }
+        let config = pay_it_forward.expect("Pay it forward keys are not enabled for this use");
+
+        let num_keys = public_keys.len();
+        require!(
+            num_keys as u32 <= config.max_keys_per_claim,
+            format!(
+                "Cannot mint more than {} keys per claim",
+                config.max_keys_per_claim
+            )
+        );
+
+        let mut source_drop = self.drop_by_id.get(source_drop_id).expect("Drop not found");
+        let keys_minted = source_drop.pay_it_forward_keys_minted + num_keys as u32;
+        require!(
+            keys_minted <= config.total_key_budget,
+            "Pay it forward key budget exhausted"
+        );
+
+        let target_drop_id = config
+            .target_drop_id
+            .clone()
+            .unwrap_or_else(|| source_drop_id.clone());
+        let target_cost_per_key = if &target_drop_id == source_drop_id {
+            self.get_pay_it_forward_cost_per_key(&source_drop)
+        } else {
+            let target_drop = self
+                .drop_by_id
+                .get(&target_drop_id)
+                .expect("Target drop not found");
+            require!(
+                target_drop.funder_id == source_drop.funder_id,
+                "Target drop must be owned by the same funder"
+            );
+            self.get_pay_it_forward_cost_per_key(&target_drop)
+        };
+
+        let reserved_cost = target_cost_per_key * num_keys as u128;
+        require!(
+            source_drop.pay_it_forward_reserve >= reserved_cost,
+            "Pay it forward reserve cannot cover the keys"
+        );
+        source_drop.pay_it_forward_keys_minted = keys_minted;
+        source_drop.pay_it_forward_reserve -= reserved_cost;
+        self.drop_by_id.insert(source_drop_id, &source_drop);
+
+        Some(PayItForwardReservation {
+            source_drop_id: source_drop_id.clone(),
+            target_drop_id,
+            public_keys,
+            reserved_cost: U128(reserved_cost),
+        })
+    }
+
+    /// Mint the keys in a pay it forward reservation. The keys are added to the target drop and owned by the claimer.
+    /// Whatever the keys didn't use out of the reservation is given back to the funder's balance.
+    pub(crate) fn internal_mint_pay_it_forward_keys(
+        &mut self,
+        event_logs: &mut Vec<EventLog>,
+        key_owner: &AccountId,
+        reservation: PayItForwardReservation,
+    ) {
+        // Measure storage usage so the funder is charged for exactly what the new keys take up
+        let initial_storage = env::storage_usage();
+
+        let PayItForwardReservation {
+            source_drop_id: _,
+            target_drop_id,
+            public_keys,
+            reserved_cost,
+        } = reservation;
+        let mut drop = self
+            .drop_by_id
+            .get(&target_drop_id)
+            .expect("Target drop not found");
+        let funder_id = drop.funder_id.clone();
+
+        let num_keys = public_keys.len();
+        let max_key_uses = drop.max_key_uses;
+        let (total_cost_per_key, total_allowance_per_key) = get_costs_per_new_key(
+            &drop.config,
+            max_key_uses,
+            &drop.asset_by_id,
+            &drop.asset_data_for_uses,
+        );
+
+        let key_data: Vec<ExtKeyData> = public_keys
+            .into_iter()
+            .map(|public_key| ExtKeyData {
+                public_key,
+                password_by_use: None,
+                metadata: None,
+                key_owner: Some(key_owner.clone()),
+                token_metadata: None,
+                time_config: None,
+            })
+            .collect();
+        self.internal_add_keys_to_account(
+            &mut drop.next_key_id,
+            &mut drop.key_info_by_token_id,
+            event_logs,
+            &target_drop_id,
+            max_key_uses,
+            &key_data,
+            total_allowance_per_key,
+        );
+        drop.update_tokens_required(key_data.len() as u128, 0, max_key_uses);
+        self.drop_by_id.insert(&target_drop_id, &drop);
+
+        // Everything was paid for when the keys were reserved so the costs are settled against the reservation
+        let net_storage = env::storage_usage() - initial_storage;
+        let costs = self.get_drop_costs(
+            &self.get_fees_for_user(&funder_id),
+            num_keys,
+            false, // No drop was created
+            total_cost_per_key,
+            total_allowance_per_key,
+            net_storage,
+        );
+        self.fees_collected += costs.fees;
+        let total_cost = costs.total();
+        if reserved_cost.0 >= total_cost {
+            self.internal_modify_user_balance(&funder_id, reserved_cost.0 - total_cost, false);
+        } else {
+            self.internal_modify_user_balance(&funder_id, total_cost - reserved_cost.0, true);
+        }
+    }
+
+    /// Give the keys in a pay it forward reservation back to the source drop's budget along with their reserved costs.
+    /// This is used when the claim that reserved them failed.
+    pub(crate) fn internal_release_pay_it_forward_keys(
+        &mut self,
+        reservation: PayItForwardReservation,
+    ) {
+        let mut source_drop = self
+            .drop_by_id
+            .get(&reservation.source_drop_id)
+            .expect("Drop not found");
+        source_drop.pay_it_forward_keys_minted -= reservation.public_keys.len() as u32;
+        source_drop.pay_it_forward_reserve += reservation.reserved_cost.0;
+        self.drop_by_id
+            .insert(&reservation.source_drop_id, &source_drop);
+    }
+
+    /// How much needs to be reserved when a drop is created to cover every key that its claims can pay forward.
+    /// Keys can be minted by any use with a pay it forward config and they all count towards the same budget so
+    /// Enough is reserved for the largest budget at the highest cost per key.
+    /// *cost_per_key* is what a new key in the drop being created costs (assets, allowance and fees).
+    pub(crate) fn get_pay_it_forward_reserve(
+        &self,
+        drop_id: Option<&DropId>,
+        funder_id: Option<&AccountId>,
+        asset_data_for_uses: &[InternalAssetDataForUses],
+        cost_per_key: Balance,
+    ) -> Balance {
+        let storage_per_key =
+            PAY_IT_FORWARD_STORAGE_RESERVE_BYTES as u128 * env::storage_byte_cost().as_yoctonear();
+
+        let mut max_budget = 0;
+        let mut max_cost_per_key = 0;
+        for config in asset_data_for_uses
+            .iter()
+            .filter_map(|data| data.config.as_ref().and_then(|c| c.pay_it_forward.as_ref()))
+        {
+            max_budget = max_budget.max(config.total_key_budget);
+
+            let target_cost_per_key = match config.target_drop_id.as_ref() {
+                Some(target_drop_id) if Some(target_drop_id) != drop_id => {
+                    let target_drop = self
+                        .drop_by_id
+                        .get(target_drop_id)
+                        .expect("Target drop not found");
+                    if let Some(funder_id) = funder_id {
+                        require!(
+                            &target_drop.funder_id == funder_id,
+                            "Target drop must be owned by the same funder"
+                        );
+                    }
+                    self.get_pay_it_forward_cost_per_key(&target_drop)
+                }
+                _ => cost_per_key + storage_per_key,
+            };
+            max_cost_per_key = max_cost_per_key.max(target_cost_per_key);
+        }
+
+        max_budget as u128 * max_cost_per_key
+    }
+
+    /// Everything a single pay it forward key added to a drop costs. This covers the key's assets, allowance
+    /// And fees along with the most storage it can take up.
+    pub(crate) fn get_pay_it_forward_cost_per_key(&self, drop: &InternalDrop) -> Balance {
+        let (total_cost_per_key, total_allowance_per_key) = get_costs_per_new_key(
+            &drop.config,
+            drop.max_key_uses,
+            &drop.asset_by_id,
+            &drop.asset_data_for_uses,
+        );
+
+        total_cost_per_key
+            + total_allowance_per_key
+            + self.get_fees_for_user(&drop.funder_id).per_key
+            + PAY_IT_FORWARD_STORAGE_RESERVE_BYTES as u128 * env::storage_byte_cost().as_yoctonear()
+    }
+}
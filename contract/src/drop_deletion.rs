@@ -24,9 +24,10 @@ impl Keypom {
         let mut drop = self.drop_by_id.remove(&drop_id).expect("No drop found");
 
         let funder_id = drop.funder_id.clone();
-        require!(
-            funder_id == env::predecessor_account_id(),
-            "Only drop funder can delete keys"
+        assert_drop_permission(
+            &drop,
+            &env::predecessor_account_id(),
+            DropOperation::delete_keys,
         );
 
         // Loop through all the assets in the drop and ensure they're empty
@@ -60,6 +61,9 @@ impl Keypom {
             .0
             * public_keys.len() as u128;
 
+        // Whatever was reserved for pay it forward keys is refunded if the drop is deleted
+        let mut pay_it_forward_refund: Balance = 0;
+
         let mut delete_key_logs = Vec::new();
         let mut nft_burn_logs = Vec::new();
 
@@ -77,6 +81,7 @@ impl Keypom {
             if let Some(owner) = key_info.owner_id.as_ref() {
                 self.internal_remove_token_from_owner(owner, &token_id);
             }
+            drop.update_tokens_required(1, key_info.remaining_uses, 0);
 
             // For every remaining use, we need to loop through all assets and refund
             get_total_costs_for_key(
@@ -115,11 +120,16 @@ impl Keypom {
         ];
 
         // Drop is empty, keep_empty_drop is false and delete_empty_drop is true
-        if drop.key_info_by_token_id.is_empty() && !keep_empty_drop.unwrap_or(false) {
+        // Drops holding crowdfund contributions are kept so contributors can still reclaim their $NEAR
+        if drop.key_info_by_token_id.is_empty()
+            && !keep_empty_drop.unwrap_or(false)
+            && (drop.crowdfund_collected || drop.near_contributions_by_sender.is_empty())
+        {
             // Now that the drop is empty, we can delete the assets by use and asset by ID
             // The drop has already been removed from storage, so we can just clear the maps
             internal_clear_drop_storage(&mut drop, &mut event_logs, &drop_id);
             self.internal_remove_drop_for_funder(&funder_id, &drop_id);
+            pay_it_forward_refund = drop.pay_it_forward_reserve;
         } else {
             // Put the modified drop back in storage
             self.drop_by_id.insert(&drop_id, &drop);
@@ -129,12 +139,14 @@ impl Keypom {
         let storage_released = initial_storage - env::storage_usage();
         let storage_refund = storage_released as u128 * env::storage_byte_cost().as_yoctonear();
 
-        let total_refund_for_use = total_cost_for_keys + total_allowance_for_keys + storage_refund;
+        let total_refund_for_use =
+            total_cost_for_keys + total_allowance_for_keys + storage_refund + pay_it_forward_refund;
         near_sdk::log!(
-            "Allowance Refund: {} Cost Refund: {} Storage Refund: {}",
+            "Allowance Refund: {} Cost Refund: {} Storage Refund: {} Pay It Forward Refund: {}",
             total_allowance_for_keys,
             total_cost_for_keys,
-            storage_refund
+            storage_refund,
+            pay_it_forward_refund
         );
         self.internal_modify_user_balance(&funder_id, total_refund_for_use, false);
 
@@ -155,6 +167,9 @@ pub(crate) fn internal_clear_drop_storage(
 ) {
     drop.asset_by_id.clear();
     drop.key_info_by_token_id.clear();
+    drop.referrals_by_account.clear();
+    drop.ft_deposits_by_sender.clear();
+    drop.near_contributions_by_sender.clear();
 
     // Add the drop deletion log to the event logs
     event_logs.push(EventLog {
@@ -0,0 +1,338 @@
+use crate::*;
+
+#[near_bindgen]
+impl Keypom {
+    /// Allows the funder to set the words that cannot be present in the name of any account
+    /// created through `create_account_and_claim` for the given drop. Passing in `None` clears the list.
+    /// Any extra storage is charged to the attached deposit first and then the funder's balance.
+    #[payable]
+    pub fn set_banned_account_words(
+        &mut self,
+        drop_id: DropId,
+        banned_words: Option<Vec<String>>,
+    ) -> bool {
+        self.assert_no_global_freeze();
+
+        let initial_storage = env::storage_usage();
+        let mut drop = self.drop_by_id.get(&drop_id).expect("No drop found");
+        require!(
+            drop.funder_id == env::predecessor_account_id(),
+            "Only drop funder can set banned account words"
+        );
+
+        // Banned words are compared against account names which are always lowercase
+        let banned_words =
+            banned_words.map(|words| words.iter().map(|w| w.to_lowercase()).collect());
+        let mut config = drop.config.unwrap_or_default();
+        config.banned_account_words = banned_words;
+        drop.config = Some(config);
+
+        self.drop_by_id.insert(&drop_id, &drop);
+
+        let refund_amount =
+            self.internal_settle_storage(initial_storage, env::attached_deposit().as_yoctonear());
+        if refund_amount > 0 {
+            Promise::new(env::predecessor_account_id())
+                .transfer(NearToken::from_yoctonear(refund_amount));
+        }
+
+        true
+    }
+
+    /// Allows the funder to give other accounts access to add keys, withdraw assets or delete keys
+    /// For the given drop. Passing in `None` removes all roles.
+    /// Any extra storage is charged to the attached deposit first and then the funder's balance.
+    #[payable]
+    pub fn set_drop_roles(&mut self, drop_id: DropId, roles: Option<DropRoles>) -> bool {
+        self.assert_no_global_freeze();
+
+        let initial_storage = env::storage_usage();
+        let mut drop = self.drop_by_id.get(&drop_id).expect("No drop found");
+        require!(
+            drop.funder_id == env::predecessor_account_id(),
+            "Only drop funder can set roles"
+        );
+
+        let mut config = drop.config.unwrap_or_default();
+        config.roles = roles;
+        drop.config = Some(config);
+
+        self.drop_by_id.insert(&drop_id, &drop);
+
+        let refund_amount =
+            self.internal_settle_storage(initial_storage, env::attached_deposit().as_yoctonear());
+        if refund_amount > 0 {
+            Promise::new(env::predecessor_account_id())
+                .transfer(NearToken::from_yoctonear(refund_amount));
+        }
+
+        true
+    }
+
+    /// Allows the funder to offer the drop to *new_funder_id*. The drop only changes hands once the new funder
+    /// Calls `accept_drop_transfer`. If *transfer_assets* is true, the FTs and NFTs escrowed in the drop go
+    /// Along with it. Otherwise they must be withdrawn before the transfer is accepted.
+    /// Passing in `None` for *new_funder_id* cancels any pending offer.
+    #[payable]
+    pub fn offer_drop_transfer(
+        &mut self,
+        drop_id: DropId,
+        new_funder_id: Option<AccountId>,
+        transfer_assets: Option<bool>,
+    ) -> bool {
+        self.assert_no_global_freeze();
+
+        let initial_storage = env::storage_usage();
+        let mut drop = self.drop_by_id.get(&drop_id).expect("No drop found");
+        require!(
+            drop.funder_id == env::predecessor_account_id(),
+            "Only drop funder can offer to transfer the drop"
+        );
+
+        drop.pending_transfer = new_funder_id.map(|new_funder_id| {
+            require!(
+                new_funder_id != drop.funder_id,
+                "Cannot transfer a drop to its current funder"
+            );
+            PendingDropTransfer {
+                new_funder_id,
+                transfer_assets: transfer_assets.unwrap_or(false),
+            }
+        });
+        self.drop_by_id.insert(&drop_id, &drop);
+
+        let refund_amount =
+            self.internal_settle_storage(initial_storage, env::attached_deposit().as_yoctonear());
+        if refund_amount > 0 {
+            Promise::new(env::predecessor_account_id())
+                .transfer(NearToken::from_yoctonear(refund_amount));
+        }
+
+        true
+    }
+
+    /// Accept a drop that was offered to the predecessor through `offer_drop_transfer`. The predecessor becomes
+    /// The drop's funder and receives all future refunds from it (as well as the funder's revenue share on resales,
+    /// So the drop's royalties must still be payable with the new funder). Any roles set by the previous funder are cleared.
+    /// Any extra storage is charged to the attached deposit first and then the new funder's balance.
+    #[payable]
+    pub fn accept_drop_transfer(&mut self, drop_id: DropId) -> bool {
+        self.assert_no_global_freeze();
+
+        let initial_storage = env::storage_usage();
+        let new_funder_id = env::predecessor_account_id();
+        let mut drop = self.drop_by_id.get(&drop_id).expect("No drop found");
+        let pending_transfer = drop
+            .pending_transfer
+            .take()
+            .expect("No pending transfer for drop");
+        require!(
+            pending_transfer.new_funder_id == new_funder_id,
+            "Drop was not offered to the predecessor"
+        );
+
+        // Escrowed FTs and NFTs are refunded to the funder so they must be withdrawn unless they're part of the transfer
+        if !pending_transfer.transfer_assets {
+            for asset in drop.asset_by_id.values() {
+                if let InternalAsset::ft(_) | InternalAsset::nft(_) = asset {
+                    require!(
+                        asset.is_empty(),
+                        "Withdraw all FTs and NFTs in the drop before accepting the transfer"
+                    );
+                }
+            }
+        }
+
+        let old_funder_id = drop.funder_id.clone();
+        drop.funder_id = new_funder_id.clone();
+        if let Some(config) = drop.config.as_mut() {
+            config.roles = None;
+
+            // The funder's revenue share follows the drop so the royalties must still be payable with the new funder
+            if let Some(nft_keys_config) = config.nft_keys_config.as_ref() {
+                assert_valid_nft_keys_config(nft_keys_config, &new_funder_id);
+            }
+        }
+        self.drop_by_id.insert(&drop_id, &drop);
+
+        self.internal_remove_drop_for_funder(&old_funder_id, &drop_id);
+        self.internal_add_drop_to_funder(&new_funder_id, &drop_id);
+
+        let refund_amount =
+            self.internal_settle_storage(initial_storage, env::attached_deposit().as_yoctonear());
+        if refund_amount > 0 {
+            Promise::new(new_funder_id.clone()).transfer(NearToken::from_yoctonear(refund_amount));
+        }
+
+        log_events(vec![EventLog {
+            standard: KEYPOM_STANDARD_NAME.to_string(),
+            version: KEYPOM_STANDARD_VERSION.to_string(),
+            event: EventLogVariant::DropTransfer(DropTransferLog {
+                old_funder_id: old_funder_id.to_string(),
+                new_funder_id: new_funder_id.to_string(),
+                drop_id,
+                transferred_assets: pending_transfer.transfer_assets,
+            }),
+        }]);
+
+        true
+    }
+
+    /// Allows the funder to change the metadata for a batch of keys in the drop. Passing in `None` for a key
+    /// Clears its metadata. Extra storage is charged to the attached deposit first and then the funder's balance.
+    /// Any storage released is refunded.
+    #[payable]
+    pub fn update_key_metadata(
+        &mut self,
+        drop_id: DropId,
+        metadata_by_token_id: HashMap<TokenId, Option<String>>,
+    ) -> bool {
+        self.internal_update_keys(
+            drop_id,
+            metadata_by_token_id,
+            "metadata",
+            |key_info, metadata| {
+                key_info.metadata = metadata;
+            },
+        )
+    }
+
+    /// Allows the funder to re-issue the passwords for a batch of keys in the drop. Just like when adding keys,
+    /// The passwords for each use should be the hex encoded double hash. Passing in `None` for a key removes its passwords.
+    /// Extra storage is charged to the attached deposit first and then the funder's balance. Any storage released is refunded.
+    #[payable]
+    pub fn update_key_passwords(
+        &mut self,
+        drop_id: DropId,
+        password_by_use_by_token_id: HashMap<TokenId, Option<HashMap<UseNumber, String>>>,
+    ) -> bool {
+        self.internal_update_keys(
+            drop_id,
+            password_by_use_by_token_id,
+            "passwords",
+            |key_info, password_by_use| {
+                key_info.pw_by_use = decode_password_by_use(&password_by_use);
+            },
+        )
+    }
+
+    /// Apply a funder-only update to a batch of keys in the drop, settle the storage with the funder
+    /// And log a key update event for every key
+    fn internal_update_keys<T>(
+        &mut self,
+        drop_id: DropId,
+        updates: HashMap<TokenId, T>,
+        updated_field: &str,
+        apply_update: impl Fn(&mut InternalKeyInfo, T),
+    ) -> bool {
+        self.assert_no_global_freeze();
+
+        let initial_storage = env::storage_usage();
+        let mut drop = self.drop_by_id.get(&drop_id).expect("No drop found");
+        let funder_id = drop.funder_id.clone();
+        require!(
+            funder_id == env::predecessor_account_id(),
+            "Only drop funder can update keys"
+        );
+        require!(
+            !updates.is_empty() && updates.len() <= 100,
+            "Must update between 1 and 100 keys at a time"
+        );
+
+        let mut update_key_logs = Vec::new();
+        for (token_id, update) in updates {
+            let mut key_info = drop
+                .key_info_by_token_id
+                .get(&token_id)
+                .expect("Key not found");
+            apply_update(&mut key_info, update);
+            drop.key_info_by_token_id.insert(&token_id, &key_info);
+
+            update_key_logs.push(UpdateKeyLog {
+                drop_id: drop_id.to_string(),
+                token_id,
+                public_key: (&key_info.pub_key).into(),
+                updated_field: updated_field.to_string(),
+            });
+        }
+        self.drop_by_id.insert(&drop_id, &drop);
+
+        let refund_amount =
+            self.internal_settle_storage(initial_storage, env::attached_deposit().as_yoctonear());
+        if refund_amount > 0 {
+            Promise::new(funder_id).transfer(NearToken::from_yoctonear(refund_amount));
+        }
+
+        log_events(vec![EventLog {
+            standard: KEYPOM_STANDARD_NAME.to_string(),
+            version: KEYPOM_STANDARD_VERSION.to_string(),
+            event: EventLogVariant::KeyUpdate(update_key_logs),
+        }]);
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use near_sdk::testing_env;
+
+    use crate::test_utils::*;
+    use crate::*;
+
+    #[test]
+    #[should_panic(expected = "Cannot have more than 9 royalty receivers")]
+    fn transfers_that_add_a_royalty_receiver_are_rejected() {
+        let mut contract = setup_contract();
+        // The funder's revenue share is merged into their own royalty so there are 9 receivers
+        let mut royalties: HashMap<String, u32> = (1..=8)
+            .map(|i| (format!("artist{}.near", i), 100))
+            .collect();
+        royalties.insert(funder_id().to_string(), 100);
+        create_drop(
+            &mut contract,
+            "royalty_drop",
+            &[public_key(1)],
+            None,
+            json!([{ "uses": 1, "assets": [null] }]),
+            json!({ "nft_keys_config": { "royalties": royalties, "funder_revenue_share": 100 } }),
+        );
+        contract.offer_drop_transfer(
+            "royalty_drop".to_string(),
+            Some("new-funder.near".parse().unwrap()),
+            None,
+        );
+
+        // The new funder's share would be a 10th receiver on top of the old funder's royalty
+        testing_env!(context(&"new-funder.near".parse().unwrap()).build());
+        contract.accept_drop_transfer("royalty_drop".to_string());
+    }
+
+    #[test]
+    fn key_updates_settle_storage_with_the_funder() {
+        let mut contract = setup_contract();
+        create_drop(
+            &mut contract,
+            "update_drop",
+            &[public_key(1)],
+            None,
+            json!([{ "uses": 1, "assets": [null] }]),
+            json!(null),
+        );
+        add_to_balance(&mut contract, &funder_id(), NearToken::from_near(1));
+        let initial_balance = contract.get_user_balance(funder_id()).0;
+
+        // Extra storage comes out of the balance when nothing is attached
+        testing_env!(context(&funder_id()).build());
+        let metadata = HashMap::from([("update_drop:0".to_string(), Some("x".repeat(100)))]);
+        contract.update_key_metadata("update_drop".to_string(), metadata);
+        let charged = initial_balance - contract.get_user_balance(funder_id()).0;
+        assert!(charged > 0);
+
+        // Released storage is refunded
+        testing_env!(context(&funder_id()).build());
+        let metadata = HashMap::from([("update_drop:0".to_string(), None)]);
+        contract.update_key_metadata("update_drop".to_string(), metadata);
+        assert_eq!(transfers_to(&funder_id()), vec![charged]);
+    }
+}
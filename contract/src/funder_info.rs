@@ -126,14 +126,14 @@ impl Keypom {
     }
 
     /// Checks that the attached deposit is greater than the required deposit and refunds any excess
-    /// If the attached deposit is less than the required deposit, decrement the user balance
-    /// If the user doesn't have enough deposit or balance, panic
+    /// If the attached deposit is less than the required deposit, decrement the given account's balance
+    /// If the account doesn't have enough deposit or balance, panic
     pub(crate) fn charge_with_deposit_or_balance(
         &mut self,
+        account_id: &AccountId,
         required_deposit: Balance,
         attached_deposit: Balance,
     ) -> Balance {
-        let predecessor = env::predecessor_account_id();
         let can_deposit_cover = attached_deposit >= required_deposit;
 
         // In the case that the attached deposit covers what is required, refund the excess
@@ -144,10 +144,36 @@ impl Keypom {
 
         // In the case that the attached deposit is less than the required, check user balance
         let required_deposit_left = required_deposit - attached_deposit;
-        self.internal_modify_user_balance(&predecessor, required_deposit_left, true);
+        self.internal_modify_user_balance(account_id, required_deposit_left, true);
         return 0;
     }
 
+    /// Charge the predecessor for any storage used since `initial_storage` or credit them for any storage released.
+    /// Returns how much of the attached deposit should be refunded to the predecessor
+    pub(crate) fn internal_settle_storage(
+        &mut self,
+        initial_storage: u64,
+        attached_deposit: Balance,
+    ) -> Balance {
+        let final_storage = env::storage_usage();
+
+        // More storage was used so we should charge the predecessor
+        if final_storage > initial_storage {
+            let storage_cost =
+                (final_storage - initial_storage) as u128 * env::storage_byte_cost().as_yoctonear();
+            return self.charge_with_deposit_or_balance(
+                &env::predecessor_account_id(),
+                storage_cost,
+                attached_deposit,
+            );
+        }
+
+        // Storage was released so the predecessor should get it back along with their deposit
+        let storage_refund =
+            (initial_storage - final_storage) as u128 * env::storage_byte_cost().as_yoctonear();
+        attached_deposit + storage_refund
+    }
+
     /// Internal function to change the user's metadata and return how much this operation cost (in
     /// terms of raw $NEAR. If they didn't attach enough but their user balance covers, this
     /// function will charge their balance and return 0)
@@ -195,30 +221,28 @@ impl Keypom {
             );
         }
 
-        // Overwrite if specified, otherwise, append (default)
+        // Overwrite if specified, otherwise, merge (default)
         if append_to_metadata.unwrap_or(true) {
-            // Append to existing metadata
-            if let Some(existing_metadata) = &funder_info.metadata {
-                let modified_existing_metadata = &existing_metadata[..&existing_metadata.len() - 1];
-                let modified_input_metadata = &new_metadata.unwrap()[1..];
-                let new_metadata =
-                    format!("{},{}", modified_existing_metadata, modified_input_metadata);
-
-                // ensure new metadata is still JSON
-                let result = serde_json::from_str::<serde_json::Value>(&new_metadata);
-                require!(
-                    result.is_ok(),
-                    format!(
-                        "New funder metadata is not valid JSON: {:?}",
-                        result.as_ref().err().unwrap()
-                    )
-                    .to_string()
-                );
-
-                funder_info.metadata = Some(new_metadata);
-            } else {
+            // Merge into the existing metadata. Keys in the new metadata replace existing ones
+            match (funder_info.metadata.take(), new_metadata) {
+                (Some(existing_metadata), Some(input_metadata)) => {
+                    let existing_value = serde_json::from_str::<Value>(&existing_metadata);
+                    let input_value = serde_json::from_str::<Value>(&input_metadata);
+                    let merged_metadata = match (existing_value, input_value) {
+                        (Ok(Value::Object(mut existing)), Ok(Value::Object(input))) => {
+                            existing.extend(input);
+                            Value::Object(existing).to_string()
+                        }
+                        _ => env::panic_str(
+                            "Funder metadata can only be merged if both are JSON objects",
+                        ),
+                    };
+                    funder_info.metadata = Some(merged_metadata);
+                }
                 // Old metadata was empty
-                funder_info.metadata = new_metadata;
+                (None, input_metadata) => funder_info.metadata = input_metadata,
+                // Nothing to merge in
+                (existing_metadata, None) => funder_info.metadata = existing_metadata,
             }
         } else {
             // Overwrite current metadata
@@ -14,6 +14,12 @@ pub(crate) fn yocto_to_near(yocto: u128) -> f64 {
     near
 }
 
+/// Get the ID that a referral reward asset is stored under in the drop. This is kept separate from the
+/// Claim assets so that referral rewards have their own funding
+pub(crate) fn get_referral_asset_id(reward: &ExtAsset) -> AssetId {
+    format!("{}{}", REFERRAL_ASSET_PREFIX, reward.get_asset_id())
+}
+
 /// Check whether an asset ID is function call or not
 pub(crate) fn is_fc_asset_id(asset_id: &AssetId) -> bool {
     asset_id.contains(FC_ASSET_PREFIX)
@@ -57,13 +63,27 @@ pub(crate) fn calculate_base_allowance(
     required_allowance
 }
 
+/// Decode the hex encoded (double hashed) passwords for each use of a key
+pub(crate) fn decode_password_by_use(
+    password_by_use: &Option<HashMap<UseNumber, String>>,
+) -> Option<HashMap<UseNumber, Vec<u8>>> {
+    password_by_use.as_ref().map(|p| {
+        p.iter()
+            .map(|(k, v)| {
+                let decoded = hex::decode(v).expect("Invalid hex string");
+                (*k, decoded)
+            })
+            .collect()
+    })
+}
+
 /// Helper function that returns the total cost for a given key as well as its allowance
 /// This key can be partially used or not
 pub(crate) fn get_total_costs_for_key(
     total_cost_for_keys: &mut Balance,
     total_allowance_for_keys: &mut Balance,
     remaining_uses: UseNumber,
-    asset_by_id: &UnorderedMap<AssetId, InternalAsset>,
+    asset_by_id: &impl AssetStore,
     asset_data_for_uses: &Vec<InternalAssetDataForUses>,
 ) {
     // Get the remaining asset data
@@ -89,16 +109,56 @@ pub(crate) fn get_total_costs_for_key(
                 BASE_GAS_FOR_CREATE_ACC_AND_CLAIM
             };
 
-        // Check and make sure that the time config is valid
-        if let Some(time_config) = use_config.as_ref().and_then(|c| c.time.as_ref()) {
-            assert_valid_time_config(time_config)
+        // Check and make sure that the account name rules are valid
+        if let Some(rules) = use_config
+            .as_ref()
+            .and_then(|c| c.account_name_rules.as_ref())
+        {
+            assert_valid_account_name_rules(rules)
+        }
+
+        // Check and make sure that the claim gate is valid
+        if let Some(claim_gate) = use_config.as_ref().and_then(|c| c.claim_gate.as_ref()) {
+            assert_valid_claim_gate(claim_gate)
+        }
+
+        // Referral rewards are paid for up front just like regular assets. We also reserve enough
+        // To cover the storage for a new referrer's reward counter
+        if let Some(referral) = use_config.as_ref().and_then(|c| c.referral.as_ref()) {
+            require!(
+                matches!(
+                    referral.reward,
+                    ExtAsset::NearAsset(_) | ExtAsset::FTAsset(_)
+                ),
+                "Referral rewards must be either $NEAR or FT assets"
+            );
+
+            let referral_asset = asset_by_id
+                .get_asset(&get_referral_asset_id(&referral.reward))
+                .expect("Referral asset not found");
+            let cost_for_use = referral_asset
+                .get_yocto_refund_amount(&Some(referral.reward.get_tokens_per_use().0))
+                + REFERRAL_STORAGE_RESERVE_BYTES as u128 * env::storage_byte_cost().as_yoctonear();
+            *total_cost_for_keys += cost_for_use * uses as u128;
+        }
+
+        // Pay it forward keys are reserved for the whole drop when it's created but the claim itself needs
+        // Enough gas (and therefore allowance) to mint the most keys possible
+        let mut pay_it_forward_gas = 0;
+        if let Some(pay_it_forward) = use_config.as_ref().and_then(|c| c.pay_it_forward.as_ref()) {
+            require!(
+                pay_it_forward.max_keys_per_claim > 0 && pay_it_forward.total_key_budget > 0,
+                "Pay it forward configs must allow at least one key"
+            );
+            pay_it_forward_gas =
+                pay_it_forward.max_keys_per_claim as u64 * GAS_PER_PAY_IT_FORWARD_KEY.as_gas();
         }
 
         // Loop through each asset metadata and tally the costs
         for metadata in assets_metadata {
             // Get the asset object (we only clear the assets by ID when the drop is empty and deleted)
             let internal_asset = asset_by_id
-                .get(&metadata.asset_id)
+                .get_asset(&metadata.asset_id)
                 .expect("Asset not found");
 
             // Get the refund amount for the asset
@@ -107,7 +167,8 @@ pub(crate) fn get_total_costs_for_key(
             *total_cost_for_keys += cost_for_use * uses as u128;
         }
 
-        let total_claim_gas = required_asset_gas.as_gas() + base_gas_for_use.as_gas();
+        let total_claim_gas =
+            required_asset_gas.as_gas() + base_gas_for_use.as_gas() + pay_it_forward_gas;
         require!(
             total_claim_gas <= MAX_GAS_ATTACHABLE.as_gas(),
             format!(
@@ -177,6 +238,32 @@ pub(crate) fn get_asset_data_for_specific_use(
     }
 }
 
+/// Assert that the account can perform the given management operation on the drop. The funder can always
+/// Manage their own drop while any other account needs the matching role in the drop's config
+pub(crate) fn assert_drop_permission(
+    drop: &InternalDrop,
+    account_id: &AccountId,
+    operation: DropOperation,
+) {
+    if &drop.funder_id == account_id {
+        return;
+    }
+
+    let roles = drop.config.as_ref().and_then(|c| c.roles.as_ref());
+    let accounts = match operation {
+        DropOperation::add_keys => roles.and_then(|r| r.key_adders.as_ref()),
+        DropOperation::withdraw_assets => roles.and_then(|r| r.asset_managers.as_ref()),
+        DropOperation::delete_keys => roles.and_then(|r| r.deleters.as_ref()),
+    };
+    require!(
+        accounts.map(|a| a.contains(account_id)).unwrap_or(false),
+        format!(
+            "{} does not have permission to {:?} for this drop",
+            account_id, operation
+        )
+    );
+}
+
 /// Take a token ID and return the drop ID and key nonce based on the `:` delimiter.
 pub(crate) fn parse_token_id(token_id: &TokenId) -> Result<(DropId, u64), String> {
     let delimiter = ":";
@@ -190,6 +277,21 @@ pub(crate) fn parse_token_id(token_id: &TokenId) -> Result<(DropId, u64), String
     return Ok((drop_id.to_string(), key_nonce.unwrap()));
 }
 
+/// Derive the implicit account ID that corresponds to a given public key.
+/// ED25519 keys map to NEAR-implicit accounts (the 64 character hex encoding of the key) while
+/// SECP256K1 keys map to ETH-implicit accounts (`0x` followed by the last 20 bytes of the keccak256 hash of the key)
+pub(crate) fn derive_implicit_account_id(public_key: &PublicKey) -> AccountId {
+    // The first byte of the public key is the curve type so we skip it
+    let key_data = &public_key.as_bytes()[1..];
+
+    let account_id = match public_key.curve_type() {
+        CurveType::ED25519 => hex::encode(key_data),
+        CurveType::SECP256K1 => format!("0x{}", hex::encode(&env::keccak256_array(key_data)[12..])),
+    };
+
+    account_id.parse().expect("Invalid implicit account ID")
+}
+
 /// Helper function to convert an external asset to an internal asset
 pub(crate) fn ext_asset_to_internal(ext_asset: Option<&ExtAsset>) -> InternalAsset {
     if let Some(asset) = ext_asset {
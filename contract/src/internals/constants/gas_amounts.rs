@@ -29,6 +29,17 @@ pub const BASE_GAS_FOR_RESOLVE_ACCOUNT_CREATION: Gas = Gas::from_gas(
         + MIN_GAS_FOR_RESOLVE_ASSET_CLAIM.as_gas(),
 );
 
+// ------------------------ Create Implicit Account & Claim ------------------------ //
+/// The base amount of gas required for create_implicit_account_and_claim calls.
+/// Implicit accounts are created by a transfer so there is no factory round trip or account
+/// Creation callback to pay for. This covers the transfer that funds the account.
+/// This does not include the actual asset promises
+pub const BASE_GAS_FOR_CREATE_IMPLICIT_ACC_AND_CLAIM: Gas =
+    Gas::from_gas(BASE_GAS_FOR_CLAIM.as_gas() + GAS_FOR_NEAR_TRANSFER.as_gas());
+/// $NEAR transferred to create an implicit account when the use being claimed doesn't send any.
+/// This is taken out of the funder's balance
+pub const IMPLICIT_ACCOUNT_FUNDING: Balance = 1_000_000_000_000_000_000_000; // 0.001 $NEAR
+
 // ------------------------ Claim ------------------------ //
 /// The base amount of gas required for claim calls.
 /// This does not include the actual asset promises
@@ -42,6 +53,44 @@ pub const MIN_GAS_FOR_RESOLVE_ASSET_CLAIM: Gas = Gas::from_gas(
     MIN_GAS_FOR_RECEIPT_COMPUTATION.as_gas() + MIN_BASE_GAS_FOR_RECEIPT_SPIN_UP.as_gas(),
 );
 
+// ------------------------ Claim Gates ------------------------ //
+/// Gas attached to the cross contract eligibility check for gated claims
+pub const GAS_FOR_CLAIM_GATE_CHECK: Gas = Gas::from_tgas(5); // 5 TGas
+/// Total gas a claim gate adds to a use. This covers the eligibility check as well as the callback that resolves it
+pub const GAS_FOR_CLAIM_GATE: Gas = Gas::from_gas(
+    GAS_FOR_CLAIM_GATE_CHECK.as_gas()
+        + 2 * MIN_BASE_GAS_FOR_RECEIPT_SPIN_UP.as_gas()
+        + MIN_GAS_FOR_RECEIPT_COMPUTATION.as_gas(),
+);
+
+// ------------------------ Referrals ------------------------ //
+/// Upper bound on the storage used by a new referrer's reward counter in a drop.
+/// This is reserved for every referral reward and whatever isn't used is given back to the funder
+pub const REFERRAL_STORAGE_RESERVE_BYTES: u64 = 400;
+
+// ------------------------ Cost Quotes ------------------------ //
+/// Estimated storage used by a drop on top of its asset data and config. This covers the drop's entry
+/// In the drop map, its collection prefixes and its entry in the funder's set of drops
+pub const ESTIMATED_BYTES_PER_DROP: u64 = 600;
+/// Estimated storage used by a key without any metadata, passwords or owner. This covers the key info,
+/// Its token ID mapping and the access key added to the contract
+pub const ESTIMATED_BYTES_PER_KEY: u64 = 450;
+
+// ------------------------ Pay It Forward ------------------------ //
+/// Gas needed to mint a single pay it forward key during a claim
+pub const GAS_PER_PAY_IT_FORWARD_KEY: Gas = Gas::from_tgas(3); // 3 TGas
+/// Upper bound on the storage used by a single pay it forward key. This covers the key info, its token ID
+/// Mapping and the claimer's set of owned tokens (which is created for their first key). Keys are minted in
+/// Callbacks where going over can't revert the claim so this leaves room for long drop IDs.
+/// Whatever isn't used is given back to the funder
+pub const PAY_IT_FORWARD_STORAGE_RESERVE_BYTES: u64 = 1_500;
+
+// ------------------------ NFT Keys ------------------------ //
+/// Gas attached to the receiver's `nft_on_transfer` when calling `nft_transfer_call`
+pub const GAS_FOR_NFT_ON_TRANSFER: Gas = Gas::from_tgas(25); // 25 TGas
+/// Gas attached to `nft_resolve_transfer` so the key can be reverted if the receiver asks for it back
+pub const GAS_FOR_RESOLVE_TRANSFER: Gas = Gas::from_tgas(15); // 15 TGas
+
 // ------------------------ Pessimistic Allowance ------------------------ //
 /// For pessimistic allowance calculations:
 /// Lowest amount of Gas a CCC can use
@@ -1,6 +1,6 @@
 // ------------------------ Access Key Method Names ------------------------ //
 pub const ACCESS_KEY_METHOD_NAMES: &str =
-    "claim,create_account_and_claim,nft_transfer,nft_approve,nft_revoke";
+    "claim,create_account_and_claim,create_implicit_account_and_claim,nft_transfer,nft_approve,nft_revoke";
 
 // ------------------------ NFT Standard Stuff ------------------------ //
 /// This spec can be treated like a version of the standard.
@@ -23,3 +23,5 @@ pub const NEAR_ASSET_ID: &str = "near";
 pub const NONE_ASSET_ID: &str = "none-asset";
 /// Prefix for all FC asset IDs
 pub const FC_ASSET_PREFIX: &str = "fc&";
+/// Prefix for all referral reward asset IDs. This is also the prefix of the `ft_on_transfer` message used to fund them
+pub const REFERRAL_ASSET_PREFIX: &str = "referral&";
@@ -39,6 +39,7 @@ pub enum EventLogVariant {
     /// Drop creation / deletion
     DropCreation(CreateOrDeleteDropLog),
     DropDeletion(CreateOrDeleteDropLog),
+    DropTransfer(DropTransferLog),
 
     /// Whenever keys are claimed
     Claim(ClaimLog),
@@ -55,6 +56,20 @@ pub enum EventLogVariant {
     /// Whenever keys are transferred
     KeyTransfer(TransferKeyLog),
     NftTransfer(Vec<NftTransferLog>),
+
+    /// Whenever keys are split or merged
+    KeyUsesTransfer(Vec<KeyUsesTransferLog>),
+
+    /// Whenever the funder updates the metadata or passwords for keys
+    KeyUpdate(Vec<UpdateKeyLog>),
+
+    /// Whenever a funder grants or revokes a spending allowance and whenever one is spent
+    AllowanceGrant(AllowanceGrantLog),
+    AllowanceRevoke(AllowanceRevokeLog),
+    AllowanceSpend(AllowanceSpendLog),
+
+    /// Whenever someone contributes towards a crowdfunded drop
+    CrowdfundContribution(CrowdfundContributionLog),
 }
 
 /// Interface to capture data about an event
@@ -12,6 +12,21 @@ pub struct CreateOrDeleteDropLog {
     pub drop_id: String,
 }
 
+/// An event log to capture whenever a drop is transferred to a new funder
+///
+/// Arguments
+/// * `old_funder_id`: "account.near"
+/// * `new_funder_id`: "new-account.near"
+/// * `drop_id`: "my-drop123"
+/// * `transferred_assets`: whether the escrowed FTs and NFTs were transferred along with the drop
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct DropTransferLog {
+    pub old_funder_id: String,
+    pub new_funder_id: String,
+    pub drop_id: String,
+    pub transferred_assets: bool,
+}
 
 /// An event log to capture whenever a key is added to or deleted from a drop
 ///
@@ -52,6 +67,109 @@ pub struct TransferKeyLog {
     pub token_id: String
 }
 
+/// An event log to capture whenever the funder updates a key's metadata or passwords
+///
+/// Arguments
+/// * `drop_id`: "my-drop123"
+/// * `token_id`: "my-drop123:0"
+/// * `public_key`: "ed25519:8WWpRpZswPHoJn5THFdBaFdbVeSjtSrQmEcoUeaNdkKy"
+/// * `updated_field`: "metadata" or "passwords"
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct UpdateKeyLog {
+    pub drop_id: String,
+    pub token_id: String,
+    pub public_key: String,
+    pub updated_field: String,
+}
+
+/// An event log to capture whenever uses are moved between keys by splitting or merging them
+///
+/// Arguments
+/// * `owner_id`: "owner.near"
+/// * `drop_id`: "my-drop123"
+/// * `old_token_id`: "my-drop123:0"
+/// * `new_token_id`: "my-drop123:1"
+/// * `uses`: how many uses were moved from the old key to the new key
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct KeyUsesTransferLog {
+    pub owner_id: String,
+    pub drop_id: String,
+    pub old_token_id: String,
+    pub new_token_id: String,
+    pub uses: UseNumber,
+}
+
+/// An event log to capture whenever a funder grants another account an allowance to spend their balance
+///
+/// Arguments
+/// * `funder_id`: "treasury.near"
+/// * `spender_id`: "account.near"
+/// * `total_limit`: most that can ever be spent (in yoctoNEAR)
+/// * `period_limit`: most that can be spent in a single period (in yoctoNEAR)
+/// * `period_length`: how long each period lasts (in nanoseconds)
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AllowanceGrantLog {
+    pub funder_id: String,
+    pub spender_id: String,
+    pub total_limit: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub period_limit: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub period_length: Option<u64>,
+}
+
+/// An event log to capture whenever a funder revokes a spending allowance
+///
+/// Arguments
+/// * `funder_id`: "treasury.near"
+/// * `spender_id`: "account.near"
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AllowanceRevokeLog {
+    pub funder_id: String,
+    pub spender_id: String,
+}
+
+/// An event log to capture whenever an account spends part of its allowance on a funder's balance
+///
+/// Arguments
+/// * `funder_id`: "treasury.near"
+/// * `spender_id`: "account.near"
+/// * `drop_id`: "my-drop123"
+/// * `amount`: how much was drawn from the funder's balance (in yoctoNEAR)
+/// * `total_spent`: how much of the allowance has been spent overall (in yoctoNEAR)
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AllowanceSpendLog {
+    pub funder_id: String,
+    pub spender_id: String,
+    pub drop_id: String,
+    pub amount: String,
+    pub total_spent: String,
+}
+
+/// An event log to capture whenever someone contributes towards a crowdfunded drop
+///
+/// Arguments
+/// * `drop_id`: "my-drop123"
+/// * `contributor_id`: "account.near"
+/// * `ft_contract_id`: FT contract the tokens came from. Not present for $NEAR contributions
+/// * `amount`: how much was contributed
+/// * `total_raised`: how much has been contributed to the drop overall
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct CrowdfundContributionLog {
+    pub drop_id: String,
+    pub contributor_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ft_contract_id: Option<String>,
+    pub amount: String,
+    pub total_raised: String,
+}
+
 /// An event log to capture whenever a key is used to call `claim`
 ///
 /// Arguments
@@ -7,20 +7,26 @@ use near_sdk::serde::ser::SerializeStruct;
 use near_sdk::serde::{Deserialize, Serialize, Serializer};
 use near_sdk::serde_json::json;
 use near_sdk::{
-    env, near_bindgen, require, AccountId, BorshStorageKey, CryptoHash, Gas, GasWeight, NearToken,
-    PanicOnDefault, Promise, PromiseOrValue, PublicKey,
+    env, near_bindgen, require, AccountId, BorshStorageKey, CryptoHash, CurveType, Gas, GasWeight,
+    NearToken, PanicOnDefault, Promise, PromiseOrValue, PublicKey,
 };
 
 mod assets;
+mod crowdfunding;
 mod drop_claiming;
 mod drop_creation;
 mod drop_deletion;
+mod drop_management;
 mod funder_info;
 mod helpers;
 mod internals;
+mod migration;
 mod models;
 mod nft_keys;
 mod owner;
+mod spending_allowances;
+#[cfg(test)]
+mod test_utils;
 mod views;
 
 use assets::*;
@@ -56,6 +62,11 @@ pub struct Keypom {
     pub drop_by_id: LookupMap<DropId, InternalDrop>,
     /// Keep track of the drop ids that each funder has created. This is used for view methods.
     pub drop_ids_by_funder: LookupMap<AccountId, UnorderedSet<DropId>>,
+    /// Counter used to assign drop IDs for each funder when one isn't specified
+    pub next_drop_nonce_by_funder: LookupMap<AccountId, u64>,
+    /// Drops created before the last migration mapped to the index of the next key to migrate.
+    /// The contract stays frozen until this is empty
+    pub drops_pending_migration: UnorderedMap<DropId, u64>,
     /// Get the token ID for any given public key
     pub token_id_by_pk: UnorderedMap<PublicKey, TokenId>,
     /// Which account should all newly created accounts be sub-accounts of? (i.e `testnet` or `near`)
@@ -64,26 +75,42 @@ pub struct Keypom {
     // ------------------------ NFT Keys ------------------------ //
     /// Keeps track of all the token IDs for a given account
     pub tokens_per_owner: LookupMap<AccountId, UnorderedSet<TokenId>>,
+    /// Collection level metadata returned by `nft_metadata`
+    pub nft_contract_metadata: NFTContractMetadata,
 
     // ------------------------ Utility ------------------------ //
     /// Keep track of the balances for each user. This is to prepay for drop creations
     pub funder_info_by_id: LookupMap<AccountId, FunderInfo>,
+    /// Allowances that each funder has granted other accounts to spend their balance
+    pub spending_allowances_by_funder:
+        LookupMap<AccountId, UnorderedMap<AccountId, InternalSpendingAllowance>>,
+    /// Named drop templates that each funder has saved
+    pub drop_templates_by_funder: LookupMap<AccountId, UnorderedMap<String, DropTemplate>>,
 }
 
 #[near_bindgen]
 impl Keypom {
     #[init]
-    pub fn new(root_account: AccountId, owner_id: AccountId) -> Self {
+    pub fn new(
+        root_account: AccountId,
+        owner_id: AccountId,
+        contract_metadata: Option<NFTContractMetadata>,
+    ) -> Self {
         Self {
             contract_owner_id: owner_id,
             global_freeze: false,
             drop_by_id: LookupMap::new(StorageKeys::DropById),
             token_id_by_pk: UnorderedMap::new(StorageKeys::TokenIdByPk),
             tokens_per_owner: LookupMap::new(StorageKeys::TokensPerOwner),
+            nft_contract_metadata: contract_metadata.unwrap_or_default(),
             funder_info_by_id: LookupMap::new(StorageKeys::FunderInfoById),
+            spending_allowances_by_funder: LookupMap::new(StorageKeys::SpendingAllowancesByFunder),
+            drop_templates_by_funder: LookupMap::new(StorageKeys::DropTemplatesByFunder),
             root_account,
             fees_per_user: LookupMap::new(StorageKeys::FeesPerUser),
             drop_ids_by_funder: LookupMap::new(StorageKeys::DropIdsByFunder),
+            next_drop_nonce_by_funder: LookupMap::new(StorageKeys::NextDropNonceByFunder),
+            drops_pending_migration: UnorderedMap::new(StorageKeys::DropsPendingMigration),
             fees_collected: 0,
             fee_structure: KeypomFees {
                 per_drop: 0,
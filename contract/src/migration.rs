@@ -0,0 +1,472 @@
+use crate::*;
+use std::collections::HashSet;
+
+/// Contract state as it was laid out before drops gained referrals, NFT key configurations and the other
+/// Per-drop state. Only used to read the old state when migrating.
+#[derive(BorshDeserialize, BorshSerialize)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct LegacyKeypom {
+    pub contract_owner_id: AccountId,
+    pub global_freeze: bool,
+    pub fee_structure: KeypomFees,
+    pub fees_collected: u128,
+    pub fees_per_user: LookupMap<AccountId, KeypomFees>,
+    pub drop_by_id: LookupMap<DropId, LegacyInternalDrop>,
+    pub drop_ids_by_funder: LookupMap<AccountId, UnorderedSet<DropId>>,
+    pub token_id_by_pk: UnorderedMap<PublicKey, TokenId>,
+    pub root_account: AccountId,
+    pub tokens_per_owner: LookupMap<AccountId, UnorderedSet<TokenId>>,
+    pub funder_info_by_id: LookupMap<AccountId, FunderInfo>,
+}
+
+/// Drop data as it was stored before the migration
+#[derive(BorshDeserialize, BorshSerialize)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct LegacyInternalDrop {
+    pub funder_id: AccountId,
+    pub max_key_uses: UseNumber,
+    pub asset_by_id: UnorderedMap<AssetId, InternalAsset>,
+    pub asset_data_for_uses: Vec<LegacyInternalAssetDataForUses>,
+    pub key_info_by_token_id: UnorderedMap<TokenId, LegacyInternalKeyInfo>,
+    pub next_key_id: u64,
+    pub config: Option<LegacyDropConfig>,
+}
+
+/// Key info as it was stored before the migration
+#[derive(BorshDeserialize, BorshSerialize)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct LegacyInternalKeyInfo {
+    pub pub_key: PublicKey,
+    pub remaining_uses: UseNumber,
+    pub owner_id: Option<AccountId>,
+    pub last_claimed: u64,
+    pub metadata: Option<String>,
+    pub pw_by_use: Option<HashMap<UseNumber, Vec<u8>>>,
+    pub approved_account_ids: HashMap<AccountId, u64>,
+    pub next_approval_id: u64,
+}
+
+/// Asset data for a set of uses as it was stored before the migration
+#[derive(BorshDeserialize, BorshSerialize)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct LegacyInternalAssetDataForUses {
+    pub uses: UseNumber,
+    pub config: Option<LegacyUseConfig>,
+    pub assets_metadata: Vec<AssetMetadata>,
+    pub required_asset_gas: Gas,
+}
+
+/// Use config as it was stored before the migration
+#[derive(BorshDeserialize, BorshSerialize)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct LegacyUseConfig {
+    pub time: Option<LegacyTimeConfig>,
+    pub permissions: Option<ClaimPermissions>,
+    pub account_creation_keypom_args: Option<KeypomInjectedArgs>,
+    pub root_account_id: Option<AccountId>,
+}
+
+/// Time config as it was stored before the migration
+#[derive(BorshDeserialize, BorshSerialize)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct LegacyTimeConfig {
+    pub start: Option<u64>,
+    pub end: Option<u64>,
+    pub throttle: Option<u64>,
+    pub interval: Option<u64>,
+}
+
+/// Drop config as it was stored before the migration
+#[derive(BorshDeserialize, BorshSerialize)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct LegacyDropConfig {
+    pub metadata: Option<DropMetadata>,
+    pub nft_keys_config: Option<LegacyNFTKeyConfigurations>,
+    pub add_key_allowlist: Option<HashSet<AccountId>>,
+    pub delete_empty_drop: Option<bool>,
+    pub extra_allowance_per_key: Option<U128>,
+}
+
+/// NFT key configurations as they were stored before the migration
+#[derive(BorshDeserialize, BorshSerialize)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct LegacyNFTKeyConfigurations {
+    pub token_metadata: Option<TokenMetadata>,
+    pub royalties: Option<HashMap<AccountId, u32>>,
+}
+
+#[near_bindgen]
+impl Keypom {
+    /// Migrate the contract state from the layout used before drops gained their extra state.
+    /// The new collections are created and the collection level NFT metadata is set to *contract_metadata*
+    /// (or the default if not specified). Every drop in *drop_ids* must then be migrated with `migrate_drop`
+    /// And the contract stays frozen until they all have been. This can only be called once.
+    #[private]
+    #[init(ignore_state)]
+    pub fn migrate(contract_metadata: Option<NFTContractMetadata>, drop_ids: Vec<DropId>) -> Self {
+        let state = env::storage_read(b"STATE").expect("No state to migrate");
+        require!(
+            Self::try_from_slice(&state).is_err(),
+            "Contract has already been migrated"
+        );
+        let old_state = LegacyKeypom::try_from_slice(&state).expect("Failed to read old state");
+
+        let mut drops_pending_migration = UnorderedMap::new(StorageKeys::DropsPendingMigration);
+        for drop_id in drop_ids {
+            require!(old_state.drop_by_id.contains_key(&drop_id), "No drop found");
+            drops_pending_migration.insert(&drop_id, &0);
+        }
+
+        Self {
+            contract_owner_id: old_state.contract_owner_id,
+            global_freeze: old_state.global_freeze || !drops_pending_migration.is_empty(),
+            fee_structure: old_state.fee_structure,
+            fees_collected: old_state.fees_collected,
+            fees_per_user: old_state.fees_per_user,
+            drop_by_id: retype(&old_state.drop_by_id),
+            drop_ids_by_funder: old_state.drop_ids_by_funder,
+            next_drop_nonce_by_funder: LookupMap::new(StorageKeys::NextDropNonceByFunder),
+            drops_pending_migration,
+            token_id_by_pk: old_state.token_id_by_pk,
+            root_account: old_state.root_account,
+            tokens_per_owner: old_state.tokens_per_owner,
+            nft_contract_metadata: contract_metadata.unwrap_or_default(),
+            funder_info_by_id: old_state.funder_info_by_id,
+            spending_allowances_by_funder: LookupMap::new(StorageKeys::SpendingAllowancesByFunder),
+            drop_templates_by_funder: LookupMap::new(StorageKeys::DropTemplatesByFunder),
+        }
+    }
+
+    /// Migrate a drop that was passed into `migrate` along with a page of its keys.
+    /// The drop itself is migrated when *from_index* is 0 (or not specified) and the rest of its keys
+    /// Are then migrated in order by calling this again with the returned *from_index*. Each drop and key
+    /// Can only be migrated once. The contract is unfrozen once the last drop has been migrated.
+    ///
+    /// Requirements:
+    /// * Only the contract owner can migrate drops.
+    ///
+    /// Arguments:
+    /// * `drop_id` the ID for the drop.
+    /// * `from_index` which key to start migrating from. If not specified, will start from 0 index.
+    /// * `limit` how many keys to migrate. If not specified, will migrate 50 keys.
+    ///
+    /// Returns the index of the next key to migrate or None if every key has been migrated
+    pub fn migrate_drop(
+        &mut self,
+        drop_id: DropId,
+        from_index: Option<U128>,
+        limit: Option<u64>,
+    ) -> Option<U128> {
+        self.assert_owner();
+        let start = u128::from(from_index.unwrap_or(U128(0))) as u64;
+        let next_index = self
+            .drops_pending_migration
+            .get(&drop_id)
+            .expect("Drop has already been migrated");
+        require!(
+            start == next_index,
+            format!("Keys must be migrated starting from index {}", next_index)
+        );
+
+        // The drop itself is only converted once, before any of its keys
+        if start == 0 {
+            // Taken out through the old layout since overwriting it would read it back as the new one
+            let mut legacy_drops: LookupMap<DropId, LegacyInternalDrop> = retype(&self.drop_by_id);
+            let legacy_drop = legacy_drops.remove(&drop_id).expect("No drop found");
+            self.drop_by_id
+                .insert(&drop_id, &legacy_drop.into_internal_drop(&drop_id));
+        }
+
+        let mut drop = self.drop_by_id.get(&drop_id).expect("No drop found");
+        let legacy_keys: UnorderedMap<TokenId, LegacyInternalKeyInfo> =
+            retype(&drop.key_info_by_token_id);
+        let end = (start + limit.unwrap_or(50)).min(legacy_keys.len());
+        for token_id in (start..end).filter_map(|i| legacy_keys.keys_as_vector().get(i)) {
+            let legacy_key_info = legacy_keys.get(&token_id).expect("Key not found");
+            drop.update_tokens_required(1, 0, legacy_key_info.remaining_uses);
+            // Written raw so the old value isn't read back as the new layout
+            drop.key_info_by_token_id.insert_raw(
+                &near_sdk::borsh::to_vec(&token_id).expect("Failed to serialize"),
+                &near_sdk::borsh::to_vec(&legacy_key_info.into_key_info(drop.max_key_uses))
+                    .expect("Failed to serialize"),
+            );
+        }
+        self.drop_by_id.insert(&drop_id, &drop);
+
+        near_sdk::log!("Migrated keys {} to {} for drop {}", start, end, drop_id);
+        if end < legacy_keys.len() {
+            self.drops_pending_migration.insert(&drop_id, &end);
+            return Some(U128(end as u128));
+        }
+
+        self.drops_pending_migration.remove(&drop_id);
+        if self.drops_pending_migration.is_empty() {
+            near_sdk::log!("Every drop has been migrated. Unfreezing the contract");
+            self.global_freeze = false;
+        }
+        None
+    }
+}
+
+impl LegacyInternalDrop {
+    /// Convert the drop into the current layout. The key info map keeps its storage prefix and
+    /// Its values are converted separately
+    fn into_internal_drop(self, drop_id: &DropId) -> InternalDrop {
+        InternalDrop {
+            funder_id: self.funder_id,
+            max_key_uses: self.max_key_uses,
+            asset_by_id: self.asset_by_id,
+            asset_data_for_uses: self
+                .asset_data_for_uses
+                .into_iter()
+                .map(|asset_data| asset_data.into())
+                .collect(),
+            tokens_required_by_asset: HashMap::new(),
+            key_info_by_token_id: retype(&self.key_info_by_token_id),
+            next_key_id: self.next_key_id,
+            config: self.config.map(|config| config.into()),
+            referrals_by_account: UnorderedMap::new(StorageKeys::ReferralsByAccount {
+                drop_id_hash: hash_string(&drop_id.to_string()),
+            }),
+            pay_it_forward_keys_minted: 0,
+            pay_it_forward_reserve: 0,
+            pending_transfer: None,
+            ft_deposits_by_sender: UnorderedMap::new(StorageKeys::FTDepositsBySender {
+                drop_id_hash: hash_string(&drop_id.to_string()),
+            }),
+            ft_contributed_by_asset: HashMap::new(),
+            crowdfund_raised: 0,
+            crowdfund_collected: false,
+            near_contributions_by_sender: UnorderedMap::new(
+                StorageKeys::NearContributionsBySender {
+                    drop_id_hash: hash_string(&drop_id.to_string()),
+                },
+            ),
+        }
+    }
+}
+
+impl LegacyInternalKeyInfo {
+    /// Convert the key info into the current layout. Keys couldn't be split before the migration so every
+    /// Use the key is missing has been claimed
+    fn into_key_info(self, max_key_uses: UseNumber) -> InternalKeyInfo {
+        InternalKeyInfo {
+            pub_key: self.pub_key,
+            remaining_uses: self.remaining_uses,
+            uses_consumed: max_key_uses - self.remaining_uses,
+            owner_id: self.owner_id,
+            last_claimed: self.last_claimed,
+            last_transferred: 0,
+            metadata: self.metadata,
+            token_metadata: None,
+            time_config: None,
+            pw_by_use: self.pw_by_use,
+            approved_account_ids: self.approved_account_ids,
+            approval_expiries: HashMap::new(),
+            next_approval_id: self.next_approval_id,
+        }
+    }
+}
+
+impl From<LegacyInternalAssetDataForUses> for InternalAssetDataForUses {
+    fn from(legacy: LegacyInternalAssetDataForUses) -> Self {
+        InternalAssetDataForUses {
+            uses: legacy.uses,
+            config: legacy.config.map(|config| config.into()),
+            assets_metadata: legacy.assets_metadata,
+            required_asset_gas: legacy.required_asset_gas,
+        }
+    }
+}
+
+impl From<LegacyUseConfig> for UseConfig {
+    fn from(legacy: LegacyUseConfig) -> Self {
+        UseConfig {
+            time: legacy.time.map(|time| time.into()),
+            permissions: legacy.permissions,
+            account_creation_keypom_args: legacy.account_creation_keypom_args,
+            root_account_id: legacy.root_account_id,
+            account_name_rules: None,
+            claim_gate: None,
+            referral: None,
+            pay_it_forward: None,
+        }
+    }
+}
+
+impl From<LegacyTimeConfig> for TimeConfig {
+    fn from(legacy: LegacyTimeConfig) -> Self {
+        TimeConfig {
+            start: legacy.start,
+            end: legacy.end,
+            throttle: legacy.throttle,
+            interval: legacy.interval,
+            schedule: None,
+        }
+    }
+}
+
+impl From<LegacyDropConfig> for DropConfig {
+    fn from(legacy: LegacyDropConfig) -> Self {
+        DropConfig {
+            metadata: legacy.metadata,
+            nft_keys_config: legacy.nft_keys_config.map(|config| config.into()),
+            add_key_allowlist: legacy.add_key_allowlist,
+            delete_empty_drop: legacy.delete_empty_drop,
+            extra_allowance_per_key: legacy.extra_allowance_per_key,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<LegacyNFTKeyConfigurations> for NFTKeyConfigurations {
+    fn from(legacy: LegacyNFTKeyConfigurations) -> Self {
+        NFTKeyConfigurations {
+            token_metadata: legacy.token_metadata,
+            royalties: legacy.royalties,
+            contract_metadata: None,
+            transfer_restrictions: None,
+            max_resale_price: None,
+            funder_revenue_share: None,
+            approved_marketplaces: None,
+        }
+    }
+}
+
+/// Reinterpret a collection with different value types. Collections only store their prefix so this
+/// Lets the same entries be read using the old or new layout.
+fn retype<T: BorshSerialize, U: BorshDeserialize>(collection: &T) -> U {
+    U::try_from_slice(&near_sdk::borsh::to_vec(collection).expect("Failed to serialize"))
+        .expect("Failed to deserialize")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::*;
+    use crate::*;
+
+    use near_sdk::testing_env;
+
+    use super::*;
+
+    /// Write contract state in the old layout with a drop that has an NFT asset for both of its uses
+    /// And two keys, one of which has already been used
+    fn write_legacy_state() {
+        testing_env!(context(&owner_id()).build());
+        let drop_id_hash = hash_string(&"legacy_drop".to_string());
+
+        let mut asset_by_id = UnorderedMap::new(StorageKeys::AssetById { drop_id_hash });
+        asset_by_id.insert(
+            &"nft_asset".to_string(),
+            &InternalAsset::nft(InternalNFTData::new("nft.near".parse().unwrap())),
+        );
+        let mut key_info_by_token_id = UnorderedMap::new(StorageKeys::KeyInfoByPk { drop_id_hash });
+        for (key_id, remaining_uses) in [(0, 2), (1, 1)] {
+            key_info_by_token_id.insert(
+                &format!("legacy_drop:{}", key_id),
+                &LegacyInternalKeyInfo {
+                    pub_key: public_key(key_id as u8 + 1),
+                    remaining_uses,
+                    owner_id: None,
+                    last_claimed: 0,
+                    metadata: None,
+                    pw_by_use: None,
+                    approved_account_ids: HashMap::new(),
+                    next_approval_id: 0,
+                },
+            );
+        }
+
+        let mut drop_by_id = LookupMap::new(StorageKeys::DropById);
+        drop_by_id.insert(
+            &"legacy_drop".to_string(),
+            &LegacyInternalDrop {
+                funder_id: funder_id(),
+                max_key_uses: 2,
+                asset_by_id,
+                asset_data_for_uses: vec![LegacyInternalAssetDataForUses {
+                    uses: 2,
+                    config: None,
+                    assets_metadata: vec![AssetMetadata {
+                        asset_id: "nft_asset".to_string(),
+                        tokens_per_use: None,
+                    }],
+                    required_asset_gas: Gas::from_tgas(10),
+                }],
+                key_info_by_token_id,
+                next_key_id: 2,
+                config: None,
+            },
+        );
+
+        env::state_write(&LegacyKeypom {
+            contract_owner_id: owner_id(),
+            global_freeze: false,
+            fee_structure: KeypomFees {
+                per_drop: 0,
+                per_key: 0,
+            },
+            fees_collected: 0,
+            fees_per_user: LookupMap::new(StorageKeys::FeesPerUser),
+            drop_by_id,
+            drop_ids_by_funder: LookupMap::new(StorageKeys::DropIdsByFunder),
+            token_id_by_pk: UnorderedMap::new(StorageKeys::TokenIdByPk),
+            root_account: "near".parse().unwrap(),
+            tokens_per_owner: LookupMap::new(StorageKeys::TokensPerOwner),
+            funder_info_by_id: LookupMap::new(StorageKeys::FunderInfoById),
+        });
+    }
+
+    #[test]
+    fn contract_stays_frozen_until_every_drop_is_migrated() {
+        write_legacy_state();
+        let mut contract = Keypom::migrate(None, vec!["legacy_drop".to_string()]);
+        assert!(contract.global_freeze);
+
+        assert_eq!(
+            contract.migrate_drop("legacy_drop".to_string(), None, Some(1)),
+            Some(U128(1))
+        );
+        assert!(contract.global_freeze);
+        assert_eq!(
+            contract.migrate_drop("legacy_drop".to_string(), Some(U128(1)), None),
+            None
+        );
+        assert!(!contract.global_freeze);
+
+        // Every use left on the keys is counted once
+        let drop = contract.drop_by_id.get(&"legacy_drop".to_string()).unwrap();
+        assert_eq!(drop.tokens_required_by_asset.get("nft_asset"), Some(&3));
+        let key_info = drop
+            .key_info_by_token_id
+            .get(&"legacy_drop:1".to_string())
+            .unwrap();
+        assert_eq!(key_info.uses_consumed, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Keys must be migrated starting from index 1")]
+    fn key_pages_cannot_be_migrated_twice() {
+        write_legacy_state();
+        let mut contract = Keypom::migrate(None, vec!["legacy_drop".to_string()]);
+        contract.migrate_drop("legacy_drop".to_string(), None, Some(1));
+        contract.migrate_drop("legacy_drop".to_string(), None, Some(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "Drop has already been migrated")]
+    fn drops_cannot_be_migrated_twice() {
+        write_legacy_state();
+        let mut contract = Keypom::migrate(None, vec!["legacy_drop".to_string()]);
+        contract.migrate_drop("legacy_drop".to_string(), None, None);
+        contract.migrate_drop("legacy_drop".to_string(), None, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Contract has already been migrated")]
+    fn contract_cannot_be_migrated_twice() {
+        write_legacy_state();
+        env::state_write(&Keypom::migrate(None, vec!["legacy_drop".to_string()]));
+        Keypom::migrate(None, vec!["legacy_drop".to_string()]);
+    }
+}
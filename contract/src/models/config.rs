@@ -30,6 +30,100 @@ pub struct UseConfig {
     /// For example, Fayyr could specify a root of `fayyr.near` By which all sub-accounts will then
     /// be `ACCOUNT.fayyr.near`
     pub root_account_id: Option<AccountId>,
+
+    /// Rules that the new account ID must follow when calling `create_account_and_claim`. This lets branded
+    /// Roots such as `fayyr.near` ensure only well formed sub-accounts are created.
+    pub account_name_rules: Option<AccountNameRules>,
+
+    /// Cross contract eligibility check that the claiming account must pass before any assets are sent.
+    /// If the check fails, the use is given back to the key.
+    pub claim_gate: Option<ClaimGate>,
+
+    /// Reward paid to whoever referred the claiming account (passed in as `referrer_id`) once the claim succeeds
+    pub referral: Option<ReferralConfig>,
+
+    /// Let the claimer mint fresh keys (owned by them as NFT keys) whenever this use is claimed.
+    /// The keys are paid for out of the funder's balance.
+    pub pay_it_forward: Option<PayItForwardConfig>,
+}
+
+/// Outlines how many new keys a claim can mint and which drop they're added to
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug)]
+#[borsh(crate = "near_sdk::borsh")]
+#[serde(crate = "near_sdk::serde")]
+pub struct PayItForwardConfig {
+    /// The most keys that can be minted by a single claim
+    pub max_keys_per_claim: u32,
+    /// The total number of keys that can be minted across all claims in the drop
+    pub total_key_budget: u32,
+    /// Which drop the keys should be added to. This must be owned by the same funder.
+    /// If None, the keys are added to the drop being claimed
+    pub target_drop_id: Option<DropId>,
+}
+
+/// Outlines the reward given to referrers whenever a key is successfully claimed through their referral
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug)]
+#[borsh(crate = "near_sdk::borsh")]
+#[serde(crate = "near_sdk::serde")]
+pub struct ReferralConfig {
+    /// What the referrer receives for every successful claim. This must be either a $NEAR or FT asset.
+    /// FT rewards are funded separately from the claim assets by calling `ft_transfer_call` with the message
+    /// `referral&<drop_id>`
+    pub reward: ExtAsset,
+    /// How many rewards a single referrer can earn in the drop. If None, there is no limit
+    pub max_rewards_per_referrer: Option<u32>,
+}
+
+#[allow(non_camel_case_types)]
+#[derive(BorshSerialize, BorshDeserialize, Deserialize, Serialize, Clone, Debug)]
+#[borsh(crate = "near_sdk::borsh")]
+#[serde(crate = "near_sdk::serde")]
+/// External check that the claiming account must pass for the assets in a use to be sent
+pub enum ClaimGate {
+    /// The claiming account must own at least 1 NFT on the given contract (`nft_supply_for_owner` > 0)
+    nft_holder { contract_id: AccountId },
+    /// The claiming account must hold at least `min_balance` fungible tokens on the given contract (`ft_balance_of`)
+    ft_balance {
+        contract_id: AccountId,
+        min_balance: U128,
+    },
+    /// The given method (`is_eligible` if not specified) is called with `{"account_id"}` and must return `true`
+    custom {
+        contract_id: AccountId,
+        method_name: Option<String>,
+    },
+}
+
+#[allow(non_camel_case_types)]
+#[derive(BorshSerialize, BorshDeserialize, Deserialize, Serialize, Clone, Debug)]
+#[borsh(crate = "near_sdk::borsh")]
+#[serde(crate = "near_sdk::serde")]
+/// Which characters are allowed in the name of a new account (excluding the root account)
+pub enum AccountNameCharset {
+    /// Lowercase letters and digits only (i.e no `-`, `_` or `.` separators)
+    alphanumeric,
+    /// Lowercase letters only
+    alphabetic,
+    /// Digits only
+    numeric,
+}
+
+/// Rules for the name of accounts created through `create_account_and_claim`. All rules are applied
+/// To the name of the account without the root (i.e `benji` for `benji.fayyr.near`)
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug)]
+#[borsh(crate = "near_sdk::borsh")]
+#[serde(crate = "near_sdk::serde")]
+pub struct AccountNameRules {
+    /// What the name must start with (if anything)
+    pub prefix: Option<String>,
+    /// What the name must end with (if anything)
+    pub suffix: Option<String>,
+    /// Minimum number of characters in the name
+    pub min_length: Option<u32>,
+    /// Maximum number of characters in the name
+    pub max_length: Option<u32>,
+    /// Which characters can be used in the name. If None, anything the root account accepts is allowed
+    pub charset: Option<AccountNameCharset>,
 }
 
 #[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug)]
@@ -54,10 +148,52 @@ pub struct TimeConfig {
     /// timestamp. The last_used timestamp is not taken into account.
     /// Measured in number of non-leap-nanoseconds since January 1, 1970 0:00:00 UTC.
     pub interval: Option<u64>,
+
+    /// Calendar based schedule (in UTC) for when the key can be used. The first period begins at the first
+    /// Occurrence of the schedule on or after the `start` timestamp. This cannot be used alongside `interval`.
+    pub schedule: Option<RecurringSchedule>,
+}
+
+/// Outlines a recurring schedule such as "the 1st of every month" that keys can be claimed on
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug)]
+#[borsh(crate = "near_sdk::borsh")]
+#[serde(crate = "near_sdk::serde")]
+pub struct RecurringSchedule {
+    /// How often a new period begins
+    pub period: SchedulePeriod,
+    /// How long (in nanoseconds) after the beginning of each period the key can be claimed.
+    /// If None, the key can be claimed at any point during the period
+    pub claim_window: Option<u64>,
+    /// What happens to the claims for periods that were skipped
+    pub missed_periods: MissedPeriods,
+}
+
+#[allow(non_camel_case_types)]
+#[derive(BorshSerialize, BorshDeserialize, Deserialize, Serialize, Clone, Debug)]
+#[borsh(crate = "near_sdk::borsh")]
+#[serde(crate = "near_sdk::serde")]
+/// When each period in a recurring schedule begins. Periods always begin at 0:00 UTC
+pub enum SchedulePeriod {
+    /// Every month on the given day (between 1 and 28 so that every month has it)
+    monthly { day_of_month: u8 },
+    /// Every week on the given day (0 is Monday and 6 is Sunday)
+    weekly { day_of_week: u8 },
+}
+
+#[allow(non_camel_case_types)]
+#[derive(BorshSerialize, BorshDeserialize, Deserialize, Serialize, Clone, Debug)]
+#[borsh(crate = "near_sdk::borsh")]
+#[serde(crate = "near_sdk::serde")]
+/// How periods that weren't claimed during their window are treated
+pub enum MissedPeriods {
+    /// The key can be claimed at most once per period. Skipped periods are lost
+    forfeit,
+    /// Skipped periods are saved up and can all be claimed during a later window
+    accumulate,
 }
 
 /// Optional configurations for the drop such as metadata, deleting empty drops etc.
-#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone)]
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Default)]
 #[borsh(crate = "near_sdk::borsh")]
 #[serde(crate = "near_sdk::serde")]
 pub struct DropConfig {
@@ -79,4 +215,69 @@ pub struct DropConfig {
     /// How much extra allowance should be given to each key in the drop?
     /// This allows keys to be used for extra functionalities such as `nft_transfer`, `nft_approve`, etc.
     pub extra_allowance_per_key: Option<U128>,
+
+    /// Words that cannot be present in the name of any account created through `create_account_and_claim`.
+    /// This can be changed by the funder at any time using `set_banned_account_words`
+    pub banned_account_words: Option<Vec<String>>,
+
+    /// Accounts that the funder has given access to manage parts of the drop. This can be changed
+    /// By the funder at any time using `set_drop_roles`
+    pub roles: Option<DropRoles>,
+
+    /// Restrictions on who can send FTs to the drop and how many. Deposits from anyone other than the funder
+    /// Are tracked so they can be reclaimed using `reclaim_ft_deposit`
+    pub ft_deposit_config: Option<FTDepositConfig>,
+
+    /// Pool the drop's funding from many contributors. Keys can't be claimed until the target is met and if
+    /// It isn't met by the deadline, contributors can reclaim what they sent
+    pub crowdfund: Option<CrowdfundConfig>,
+}
+
+/// Funding target and deadline for a crowdfunded drop
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Debug)]
+#[borsh(crate = "near_sdk::borsh")]
+#[serde(crate = "near_sdk::serde")]
+pub struct CrowdfundConfig {
+    /// FT contract whose tokens are being raised. These must be one of the drop's FT assets.
+    /// If not set, $NEAR is raised using `contribute_to_drop`
+    pub ft_contract_id: Option<AccountId>,
+    /// How much needs to be contributed before keys can be claimed
+    pub target: U128,
+    /// Block timestamp that the target must be met by
+    pub deadline: u64,
+}
+
+/// Optional strictness for FTs sent to a drop through `ft_transfer_call`
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Debug, Default)]
+#[borsh(crate = "near_sdk::borsh")]
+#[serde(crate = "near_sdk::serde")]
+pub struct FTDepositConfig {
+    /// Accounts other than the funder that can send FTs to the drop. If not set, only the funder can.
+    /// Each sender pays for the storage to keep track of their deposits out of their Keypom balance
+    pub allowed_senders: Option<HashSet<AccountId>>,
+    /// Whether any tokens beyond what's needed to cover every remaining use of every key should be returned
+    pub return_excess: Option<bool>,
+}
+
+/// Accounts that can perform specific management operations on a drop on behalf of the funder.
+/// Any refunds from these operations are still sent to the funder
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Debug, Default)]
+#[borsh(crate = "near_sdk::borsh")]
+#[serde(crate = "near_sdk::serde")]
+pub struct DropRoles {
+    /// Accounts that can add keys to the drop. They pay for the keys themselves
+    pub key_adders: Option<HashSet<AccountId>>,
+    /// Accounts that can withdraw FTs and NFTs from the drop back to the funder
+    pub asset_managers: Option<HashSet<AccountId>>,
+    /// Accounts that can delete keys from the drop
+    pub deleters: Option<HashSet<AccountId>>,
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug)]
+/// Management operations on a drop that can be delegated through `DropRoles`
+pub enum DropOperation {
+    add_keys,
+    withdraw_assets,
+    delete_keys,
 }
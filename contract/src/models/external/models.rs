@@ -96,6 +96,12 @@ pub struct ExtDrop {
 
     /// Keep track of the next nonce to give out to a key
     pub next_key_id: u64,
+
+    /// Account that the funder has offered to transfer the drop to
+    pub pending_funder_id: Option<AccountId>,
+
+    /// Whether the drop holds enough FTs and NFTs to cover every remaining use of every key
+    pub fully_funded: bool,
 }
 
 /// Data for each key coming in (public key, password, metadata, owner etc.)
@@ -113,4 +119,147 @@ pub struct ExtKeyData {
     pub metadata: Option<String>,
     /// What account ID owns the given key (if any)
     pub key_owner: Option<AccountId>,
+    /// NFT token metadata for this specific key. This takes precedence over the drop's `nft_keys_config`
+    pub token_metadata: Option<TokenMetadata>,
+    /// Time configuration for this specific key. This takes precedence over the time config in the use's config
+    pub time_config: Option<TimeConfig>,
+}
+
+/// Referral data being returned from view calls from Keypom
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ExtReferralStats {
+    /// Account that referred the claims
+    pub referrer_id: AccountId,
+    /// How many referral rewards the account has earned in the drop
+    pub num_rewards: u32,
+}
+
+/// Spending allowance data being returned from view calls from Keypom
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ExtSpendingAllowance {
+    /// Account that can draw from the funder's balance
+    pub spender_id: AccountId,
+    /// The most that can ever be drawn from the funder's balance
+    pub total_limit: U128,
+    /// How much has been drawn from the funder's balance so far
+    pub total_spent: U128,
+    /// The most that can be drawn from the funder's balance in a single period
+    pub period_limit: Option<U128>,
+    /// How long each period lasts (in nanoseconds)
+    pub period_length: Option<u64>,
+    /// When the current period began
+    pub period_start: u64,
+    /// How much has been drawn from the funder's balance in the current period
+    pub period_spent: U128,
+}
+
+/// Overrides applied when creating a drop from an existing drop or a saved template
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct DropCloneOverrides {
+    /// Keys to add to the new drop as it's created
+    pub key_data: Option<Vec<ExtKeyData>>,
+    /// Replaces the entire drop config
+    pub drop_config: Option<DropConfig>,
+    /// Replaces only the metadata in the drop config
+    pub metadata: Option<DropMetadata>,
+}
+
+/// Drop template data being returned from view calls from Keypom
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ExtDropTemplate {
+    /// Name that the funder saved the template under
+    pub template_name: String,
+    /// Asset data for every set of uses in the drop
+    pub asset_data: Vec<ExtAssetDataForUses>,
+    /// Configurations for the drop
+    pub drop_config: Option<DropConfig>,
+}
+
+/// Breakdown of the costs for creating a drop being returned from Keypom
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ExtDropCosts {
+    /// Sum of every cost below
+    pub total_cost: U128,
+    /// Cost of the storage used
+    pub storage_cost: U128,
+    /// Cost of the assets for every use of every key
+    pub asset_cost: U128,
+    /// Allowance given to every key
+    pub allowance_cost: U128,
+    /// Keypom fees charged for the drop and keys
+    pub fees: U128,
+    /// Reserved up front for the keys that claims in the drop can pay forward
+    pub pay_it_forward_cost: U128,
+}
+
+/// Result for every drop created in `create_drop_batch`
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ExtDropCreationResult {
+    /// ID of the drop that was created
+    pub drop_id: DropId,
+    /// What the funder was charged for the drop
+    pub costs: ExtDropCosts,
+}
+
+/// Quote for the costs of creating a drop or adding keys being returned from Keypom
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ExtCostQuote {
+    /// Breakdown of the costs. Storage is based on the estimated bytes
+    pub costs: ExtDropCosts,
+    /// Fee structure used for the quote
+    pub fee_structure: KeypomFees,
+    /// Estimated number of bytes that will be used
+    pub estimated_storage_bytes: u64,
+}
+
+/// Where a crowdfunded drop is at
+#[allow(non_camel_case_types)]
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub enum CrowdfundStatus {
+    /// Contributions are being accepted and keys cannot be claimed yet
+    open,
+    /// The target was met so keys can be claimed
+    funded,
+    /// The deadline passed without the target being met so contributors can reclaim what they sent
+    failed,
+}
+
+/// Progress of a crowdfunded drop being returned from view calls from Keypom
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ExtCrowdfundStatus {
+    pub status: CrowdfundStatus,
+    /// FT contract whose tokens are being raised. If not set, $NEAR is being raised
+    pub ft_contract_id: Option<AccountId>,
+    pub target: U128,
+    pub raised: U128,
+    pub deadline: u64,
+    /// Whether the funder has collected what was raised
+    pub collected: bool,
+}
+
+/// Funding status for a single FT or NFT asset in a drop being returned from view calls from Keypom
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ExtAssetFundingStatus {
+    /// ID of the asset in the drop. Referral rewards are prefixed with `referral&`
+    pub asset_id: AssetId,
+    /// Contract that the tokens are from
+    pub contract_id: AccountId,
+    /// Tokens needed to cover every remaining use of every key. For NFTs, this is the number of tokens
+    pub required: U128,
+    /// Tokens currently held by the drop
+    pub held: U128,
+    /// How many more tokens need to be sent to the drop
+    pub shortfall: U128,
+    /// How many tokens the drop holds beyond what's needed
+    pub surplus: U128,
 }
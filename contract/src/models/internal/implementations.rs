@@ -13,6 +13,28 @@ impl InternalDrop {
             _ => {}
         });
 
+        ExtDrop {
+            drop_id,
+            asset_data: self.get_ext_asset_data(),
+            nft_asset_data: nft_list,
+            ft_asset_data: ft_list,
+            drop_config: self.config.clone(),
+            funder_id: self.funder_id.clone(),
+            max_key_uses: self.max_key_uses,
+            next_key_id: self.next_key_id,
+            pending_funder_id: self
+                .pending_transfer
+                .as_ref()
+                .map(|t| t.new_funder_id.clone()),
+            fully_funded: self
+                .get_funding_status()
+                .iter()
+                .all(|status| status.shortfall.0 == 0),
+        }
+    }
+
+    /// Get the assets and use configs for every set of uses in the drop as they would be passed in when creating it
+    pub fn get_ext_asset_data(&self) -> Vec<ExtAssetDataForUses> {
         let mut asset_data = vec![];
 
         for internal_asset in self.asset_data_for_uses.iter() {
@@ -32,16 +54,114 @@ impl InternalDrop {
             })
         }
 
-        ExtDrop {
-            drop_id,
-            asset_data,
-            nft_asset_data: nft_list,
-            ft_asset_data: ft_list,
-            drop_config: self.config.clone(),
-            funder_id: self.funder_id.clone(),
-            max_key_uses: self.max_key_uses,
-            next_key_id: self.next_key_id,
+        asset_data
+    }
+
+    /// Get where the drop's crowdfund is at. Returns `None` if the drop isn't crowdfunded.
+    /// Contributions stop counting once the crowdfund is no longer open so its status can't change after that
+    pub fn get_crowdfund_status(&self) -> Option<CrowdfundStatus> {
+        let crowdfund = self.config.as_ref()?.crowdfund.as_ref()?;
+
+        Some(if self.crowdfund_raised >= crowdfund.target.0 {
+            CrowdfundStatus::funded
+        } else if env::block_timestamp() > crowdfund.deadline {
+            CrowdfundStatus::failed
+        } else {
+            CrowdfundStatus::open
+        })
+    }
+
+    /// For every FT and NFT asset in the drop, compare the tokens needed to cover every remaining use
+    /// Of every key against what the drop currently holds
+    pub fn get_funding_status(&self) -> Vec<ExtAssetFundingStatus> {
+        self.asset_by_id
+            .iter()
+            .filter_map(|(asset_id, asset)| {
+                let (contract_id, held) = match asset {
+                    InternalAsset::ft(ft_data) => (ft_data.contract_id, ft_data.balance_avail),
+                    InternalAsset::nft(nft_data) => {
+                        (nft_data.contract_id, nft_data.token_ids.len() as u128)
+                    }
+                    _ => return None,
+                };
+                let required = self
+                    .tokens_required_by_asset
+                    .get(&asset_id)
+                    .copied()
+                    .unwrap_or(0);
+
+                Some(ExtAssetFundingStatus {
+                    asset_id,
+                    contract_id,
+                    required: U128(required),
+                    held: U128(held),
+                    shortfall: U128(required.saturating_sub(held)),
+                    surplus: U128(held.saturating_sub(required)),
+                })
+            })
+            .collect()
+    }
+
+    /// Take FTs that a contributor reclaimed out of the total that contributors have deposited for an asset
+    pub fn remove_ft_contribution(&mut self, asset_id: &AssetId, amount: Balance) {
+        if let Some(contributed) = self.ft_contributed_by_asset.get_mut(asset_id) {
+            *contributed = contributed.saturating_sub(amount);
+            if *contributed == 0 {
+                self.ft_contributed_by_asset.remove(asset_id);
+            }
+        }
+    }
+
+    /// Keep the tokens required by every FT and NFT asset up to date when *num_keys* keys go from having
+    /// *old_remaining_uses* to *new_remaining_uses*. New keys start at 0 and deleted keys end at 0
+    pub fn update_tokens_required(
+        &mut self,
+        num_keys: u128,
+        old_remaining_uses: UseNumber,
+        new_remaining_uses: UseNumber,
+    ) {
+        for (asset_id, tokens) in self.get_tokens_required_for_key(old_remaining_uses) {
+            let required = self.tokens_required_by_asset.entry(asset_id).or_insert(0);
+            *required = required.saturating_sub(tokens * num_keys);
+        }
+        for (asset_id, tokens) in self.get_tokens_required_for_key(new_remaining_uses) {
+            *self.tokens_required_by_asset.entry(asset_id).or_insert(0) += tokens * num_keys;
         }
+        self.tokens_required_by_asset
+            .retain(|_, required| *required > 0);
+    }
+
+    /// Tokens of every FT and NFT asset needed to cover the remaining uses of a single key
+    fn get_tokens_required_for_key(&self, remaining_uses: UseNumber) -> HashMap<AssetId, Balance> {
+        let mut required_by_asset: HashMap<AssetId, Balance> = HashMap::new();
+        if remaining_uses == 0 {
+            return required_by_asset;
+        }
+
+        for asset_data in get_remaining_asset_data(&self.asset_data_for_uses, remaining_uses) {
+            let uses = asset_data.uses as u128;
+
+            // NFTs are always 1 token per use while FTs have a set amount
+            for metadata in asset_data.assets_metadata {
+                let tokens_per_use = match self.asset_by_id.get(&metadata.asset_id) {
+                    Some(InternalAsset::ft(_)) => metadata.tokens_per_use.map(|t| t.0).unwrap_or(0),
+                    Some(InternalAsset::nft(_)) => 1,
+                    _ => continue,
+                };
+                *required_by_asset.entry(metadata.asset_id).or_insert(0) += tokens_per_use * uses;
+            }
+
+            // Referral rewards are assumed to be paid out for every use
+            if let Some(referral) = asset_data.config.and_then(|c| c.referral) {
+                if let ExtAsset::FTAsset(_) = referral.reward {
+                    *required_by_asset
+                        .entry(get_referral_asset_id(&referral.reward))
+                        .or_insert(0) += referral.reward.get_tokens_per_use().0 * uses;
+                }
+            }
+        }
+
+        required_by_asset
     }
 }
 
@@ -209,3 +329,37 @@ impl InternalAsset {
         }
     }
 }
+
+impl InternalSpendingAllowance {
+    /// Convert an `InternalSpendingAllowance` into an `ExtSpendingAllowance`
+    pub fn to_external(&self, spender_id: AccountId) -> ExtSpendingAllowance {
+        ExtSpendingAllowance {
+            spender_id,
+            total_limit: U128(self.total_limit),
+            total_spent: U128(self.total_spent),
+            period_limit: self.period_limit.map(U128),
+            period_length: self.period_length,
+            period_start: self.period_start,
+            period_spent: U128(self.period_spent),
+        }
+    }
+}
+
+impl DropCosts {
+    /// Total cost across storage, assets, allowance, fees and the pay it forward reserve
+    pub fn total(&self) -> Balance {
+        self.storage + self.assets + self.allowance + self.fees + self.pay_it_forward
+    }
+
+    /// Convert `DropCosts` into `ExtDropCosts`
+    pub fn to_external(&self) -> ExtDropCosts {
+        ExtDropCosts {
+            total_cost: U128(self.total()),
+            storage_cost: U128(self.storage),
+            asset_cost: U128(self.assets),
+            allowance_cost: U128(self.allowance),
+            fees: U128(self.fees),
+            pay_it_forward_cost: U128(self.pay_it_forward),
+        }
+    }
+}
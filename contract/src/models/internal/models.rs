@@ -13,6 +13,9 @@ pub struct InternalDrop {
     pub asset_by_id: UnorderedMap<AssetId, InternalAsset>,
     /// For every use, keep track of what assets there are (in order)
     pub asset_data_for_uses: Vec<InternalAssetDataForUses>,
+    /// How many tokens of each FT and NFT asset are needed to cover every remaining use of every key.
+    /// Kept up to date as keys are added, claimed and deleted so the drop's funding can be checked cheaply
+    pub tokens_required_by_asset: HashMap<AssetId, Balance>,
 
     /// Set of public keys associated with this drop mapped to their specific key information.
     pub key_info_by_token_id: UnorderedMap<TokenId, InternalKeyInfo>,
@@ -21,6 +24,51 @@ pub struct InternalDrop {
 
     /// Keep track of different configuration options for all the uses of a key in a given drop
     pub config: Option<DropConfig>,
+
+    /// How many referral rewards each referrer has earned in this drop
+    pub referrals_by_account: UnorderedMap<AccountId, u32>,
+
+    /// How many pay it forward keys have been minted by claims in this drop
+    pub pay_it_forward_keys_minted: u32,
+    /// What the funder has left reserved to pay for the keys that claims in this drop can still pay forward
+    pub pay_it_forward_reserve: Balance,
+
+    /// Transfer of the drop that the funder has offered and is waiting to be accepted
+    pub pending_transfer: Option<PendingDropTransfer>,
+
+    /// How many FTs each sender (other than the funder) has deposited for an asset and can still reclaim
+    pub ft_deposits_by_sender: UnorderedMap<(AccountId, AssetId), Balance>,
+    /// Total FTs of each asset that contributors have deposited and can still reclaim
+    pub ft_contributed_by_asset: HashMap<AssetId, Balance>,
+
+    /// Total contributed towards the crowdfund target if the drop is crowdfunded
+    pub crowdfund_raised: Balance,
+    /// Whether the funder has collected what the crowdfund raised
+    pub crowdfund_collected: bool,
+    /// How much $NEAR each account has contributed to the drop's crowdfund and not yet reclaimed
+    pub near_contributions_by_sender: UnorderedMap<AccountId, Balance>,
+}
+
+/// The shape of a drop (assets, use configs and drop config) that a funder has saved to create new drops from.
+/// No balances or token IDs are stored, only the asset definitions
+#[derive(BorshDeserialize, BorshSerialize, Clone)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct DropTemplate {
+    /// Asset data for every set of uses in the drop
+    pub asset_data: Vec<ExtAssetDataForUses>,
+    /// Configurations for the drop
+    pub config: Option<DropConfig>,
+}
+
+/// A transfer of a drop that the funder has offered to another account
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct PendingDropTransfer {
+    /// Account that can accept the drop and become its funder
+    pub new_funder_id: AccountId,
+    /// Whether the FTs and NFTs escrowed in the drop go along with it. If not, they must be
+    /// Withdrawn by the current funder before the transfer can be accepted
+    pub transfer_assets: bool,
 }
 
 /// Keep track of different configuration options for each key in a drop
@@ -33,18 +81,31 @@ pub struct InternalKeyInfo {
     /// How many uses this key has left. Once 0 is reached, the key is deleted
     pub remaining_uses: UseNumber,
 
+    /// How many of this key's uses have been claimed. This is where the key is in its schedule and intervals.
+    /// Keys minted by splitting start from the original key's count and merged keys keep the highest count
+    pub uses_consumed: UseNumber,
+
     /// Owner of the key
     pub owner_id: Option<AccountId>,
 
     /// When was the last time the key was used
     pub last_claimed: u64,
 
+    /// When was the last time the key was transferred
+    pub last_transferred: u64,
+
     /// Metadata for the current key
     pub metadata: Option<String>,
+    /// NFT token metadata for this key that overrides the drop level metadata
+    pub token_metadata: Option<TokenMetadata>,
+    /// Time configuration for this key that overrides the one in the use's config
+    pub time_config: Option<TimeConfig>,
     pub pw_by_use: Option<HashMap<UseNumber, Vec<u8>>>,
 
     /// List of approved account IDs that have access to transfer the token. This maps an account ID to an approval ID
     pub approved_account_ids: HashMap<AccountId, u64>,
+    /// When (if ever) each approval stops being valid, as a block timestamp in nanoseconds
+    pub approval_expiries: HashMap<AccountId, u64>,
 
     /// The next approval ID to give out.
     pub next_approval_id: u64,
@@ -62,6 +123,25 @@ pub struct FunderInfo {
     pub metadata: Option<String>,
 }
 
+/// An allowance that a funder has granted another account to draw from the funder's balance
+/// When creating drops or adding keys on the funder's behalf
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct InternalSpendingAllowance {
+    /// The most that can ever be drawn from the funder's balance
+    pub total_limit: Balance,
+    /// How much has been drawn from the funder's balance so far
+    pub total_spent: Balance,
+    /// The most that can be drawn from the funder's balance in a single period
+    pub period_limit: Option<Balance>,
+    /// How long each period lasts (in nanoseconds)
+    pub period_length: Option<u64>,
+    /// When the current period began
+    pub period_start: u64,
+    /// How much has been drawn from the funder's balance in the current period
+    pub period_spent: Balance,
+}
+
 /// Outlines the asset data for a set of uses
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
 #[borsh(crate = "near_sdk::borsh")]
@@ -110,6 +190,31 @@ impl From<&ExtAssetDataForUses> for InternalAssetDataForUses {
             });
         }
 
+        // Gated uses need to pay for the eligibility check on top of the assets
+        if ext_asset_data
+            .config
+            .as_ref()
+            .and_then(|c| c.claim_gate.as_ref())
+            .is_some()
+        {
+            total_required_asset_gas = total_required_asset_gas
+                .checked_add(GAS_FOR_CLAIM_GATE)
+                .unwrap();
+        }
+
+        // Referral rewards are paid out after the claim is resolved and need gas for the transfer and its callback
+        if let Some(referral) = ext_asset_data
+            .config
+            .as_ref()
+            .and_then(|c| c.referral.as_ref())
+        {
+            let referral_gas = referral.reward.to_internal_asset().get_total_required_gas();
+            total_required_asset_gas = total_required_asset_gas
+                .checked_add(referral_gas)
+                .and_then(|g| g.checked_add(MIN_GAS_FOR_RESOLVE_ASSET_CLAIM))
+                .unwrap();
+        }
+
         Self {
             uses: ext_asset_data.uses,
             config: ext_asset_data.config.clone(),
@@ -166,6 +271,47 @@ pub struct KeypomFees {
     pub per_key: u128,
 }
 
+/// Breakdown of what a funder was charged when creating a drop or adding keys
+#[derive(Clone, Debug, Default)]
+pub struct DropCosts {
+    /// Cost of the storage used
+    pub storage: Balance,
+    /// Cost of the assets for every use of every key
+    pub assets: Balance,
+    /// Allowance given to every key
+    pub allowance: Balance,
+    /// Keypom fees charged for the drop and keys
+    pub fees: Balance,
+    /// Reserved up front for the keys that claims in the drop can pay forward
+    pub pay_it_forward: Balance,
+}
+
+/// Pay it forward keys that a claim has taken out of the source drop's budget and that are waiting to be minted
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct PayItForwardReservation {
+    /// Drop whose budget and reserve the keys were taken from
+    pub source_drop_id: DropId,
+    /// Drop that the keys will be added to
+    pub target_drop_id: DropId,
+    /// Public keys to mint
+    pub public_keys: Vec<PublicKey>,
+    /// How much of the source drop's reserve was set aside for the keys
+    pub reserved_cost: U128,
+}
+
+/// Everything a claim set aside before its claim gate was checked. It's used once the gate passes or given back if it fails
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct PendingClaim {
+    /// When the key was last claimed before this use. Restored if the claim gate fails
+    pub previous_last_claimed: u64,
+    /// $NEAR taken out of the funder's balance to create the implicit account being claimed to
+    pub implicit_account_funding: Option<U128>,
+    /// Pay it forward keys reserved for the claimer. They're only minted once the claim gate passes
+    pub pay_it_forward_reservation: Option<PayItForwardReservation>,
+}
+
 /// Data returned from the `before_claim_logic` function
 pub struct BeforeClaimData {
     pub drop_id: DropId,
@@ -179,6 +325,12 @@ pub struct BeforeClaimData {
     pub root_account_id: AccountId,
     /// When calling `create_account` on the root account, which keypom args should be attached to the payload.
     pub account_creation_keypom_args: Option<KeypomInjectedArgs>,
+    /// Whether or not the claimer can mint new keys as part of this use
+    pub pay_it_forward: Option<PayItForwardConfig>,
+    /// Whether the use is behind a claim gate that needs to be checked before the assets go out
+    pub is_gated: bool,
+    /// When the key was last claimed before this use. Restored if the claim gate fails
+    pub previous_last_claimed: u64,
 }
 
 #[derive(BorshSerialize, BorshStorageKey)]
@@ -194,4 +346,13 @@ pub enum StorageKeys {
     DropById,
     TokenIdByPk,
     FunderInfoById,
+    SpendingAllowancesByFunder,
+    SpendingAllowancesByFunderInner { account_id_hash: CryptoHash },
+    DropTemplatesByFunder,
+    DropTemplatesByFunderInner { account_id_hash: CryptoHash },
+    NextDropNonceByFunder,
+    ReferralsByAccount { drop_id_hash: CryptoHash },
+    FTDepositsBySender { drop_id_hash: CryptoHash },
+    NearContributionsBySender { drop_id_hash: CryptoHash },
+    DropsPendingMigration,
 }
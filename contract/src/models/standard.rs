@@ -30,6 +30,11 @@ pub struct ExtKeyInfo {
     pub owner_id: AccountId,
     pub metadata: Option<String>,
     pub fc_list: Vec<FCData>,
+    /// Time configuration specific to this key (if any). This overrides the time config for the current use
+    pub time_config: Option<TimeConfig>,
+    /// If the key follows a recurring schedule, the next timestamp it can be claimed at.
+    /// This is the beginning of the current period if the key can be claimed right now
+    pub next_claimable_timestamp: Option<u64>,
 
     pub uses_remaining: UseNumber,
 }
@@ -2,15 +2,20 @@ use crate::*;
 
 #[near_bindgen]
 impl Keypom {
-    /// Allow a specific account ID to transfer a token on your behalf
+    /// Allow a specific account ID to transfer a token on your behalf.
+    /// If *expires_at* is passed in, the approval can no longer be used after that block timestamp (in nanoseconds).
+    /// The storage for the approval is paid for by the caller with their attached deposit or balance.
+    /// If the key signs for itself, the storage is paid for out of the drop funder's balance instead.
     #[payable]
     pub fn nft_approve(
         &mut self,
         token_id: Option<TokenId>,
         account_id: AccountId,
         msg: Option<String>,
+        expires_at: Option<u64>,
     ) {
         self.assert_no_global_freeze();
+        let initial_storage = env::storage_usage();
 
         let sender_id = env::predecessor_account_id();
         let sender_pk = env::signer_account_pk();
@@ -31,19 +36,52 @@ impl Keypom {
 
         // Check that the sender is the owner of the token.
         // If the token is owned by keypom, decrement the key's allowance
-        check_key_owner(sender_id, &key_info);
+        check_key_owner(sender_id.clone(), &key_info);
+
+        let nft_keys_config = drop
+            .config
+            .as_ref()
+            .and_then(|c| c.nft_keys_config.as_ref());
+
+        // Don't hand out approvals that could never be used to transfer the key
+        if let Some(restrictions) = nft_keys_config.and_then(|c| c.transfer_restrictions.as_ref()) {
+            restrictions.assert_transferable(&key_info);
+        }
+
+        // Only approved marketplaces can be given access to keys in the drop
+        if let Some(marketplaces) = nft_keys_config.and_then(|c| c.approved_marketplaces.as_ref()) {
+            require!(
+                marketplaces.contains(&account_id),
+                "Account is not an approved marketplace for this drop"
+            );
+        }
+
+        if let Some(expires_at) = expires_at {
+            require!(
+                expires_at > env::block_timestamp(),
+                "Approval cannot expire in the past"
+            );
+        }
 
         //get the next approval ID if we need a new approval
         let approval_id: u64 = key_info.next_approval_id;
         key_info
             .approved_account_ids
             .insert(account_id.clone(), approval_id);
+        match expires_at {
+            Some(expires_at) => key_info
+                .approval_expiries
+                .insert(account_id.clone(), expires_at),
+            None => key_info.approval_expiries.remove(&account_id),
+        };
         key_info.next_approval_id += 1;
 
         // Reinsert key info mapping to NFT and then add token ID mapping to public key
         drop.key_info_by_token_id.insert(&token_id, &key_info);
         self.drop_by_id.insert(&drop_id, &drop);
 
+        self.internal_settle_owner_storage(&sender_id, &drop.funder_id, initial_storage);
+
         //if some message was passed into the function, we initiate a cross contract call on the
         //account we're giving access to.
         if let Some(msg) = msg {
@@ -76,6 +114,11 @@ impl Keypom {
             .get(&token_id)
             .expect("Key info not found");
 
+        // Expired approvals can't be used
+        if approval_is_expired(&key_info, &approved_account_id) {
+            return false;
+        }
+
         //get the approval number for the passed in account ID
         let approval = key_info.approved_account_ids.get(&approved_account_id);
 
@@ -99,6 +142,7 @@ impl Keypom {
     #[payable]
     pub fn nft_revoke(&mut self, token_id: Option<TokenId>, account_id: AccountId) {
         self.assert_no_global_freeze();
+        let initial_storage = env::storage_usage();
 
         let sender_id = env::predecessor_account_id();
         let sender_pk = env::signer_account_pk();
@@ -119,15 +163,95 @@ impl Keypom {
 
         // Check that the sender is the owner of the token.
         // If the token is owned by keypom, decrement the key's allowance
-        check_key_owner(sender_id, &key_info);
+        check_key_owner(sender_id.clone(), &key_info);
 
         //if the account ID was in the token's approval, we remove it and the if statement logic executes
         if key_info.approved_account_ids.remove(&account_id).is_some() {
+            key_info.approval_expiries.remove(&account_id);
+            // Reinsert key info mapping to NFT and then add token ID mapping to public key
+            drop.key_info_by_token_id.insert(&token_id, &key_info);
+            self.drop_by_id.insert(&drop_id, &drop);
+        }
+
+        self.internal_settle_owner_storage(&sender_id, &drop.funder_id, initial_storage);
+    }
+
+    //revoke all accounts from transferring the token on your behalf
+    #[payable]
+    pub fn nft_revoke_all(&mut self, token_id: Option<TokenId>) {
+        self.assert_no_global_freeze();
+        let initial_storage = env::storage_usage();
+
+        let sender_id = env::predecessor_account_id();
+        let sender_pk = env::signer_account_pk();
+
+        // Token ID is either from sender PK or passed in
+        let token_id = self
+            .token_id_by_pk
+            .get(&sender_pk)
+            .unwrap_or_else(|| token_id.expect("Token ID not provided"));
+        let drop_id = parse_token_id(&token_id).unwrap().0;
+
+        // Get drop in order to get key info
+        let mut drop = self.drop_by_id.get(&drop_id).expect("Drop not found");
+        let mut key_info = drop
+            .key_info_by_token_id
+            .get(&token_id)
+            .expect("Key info not found");
+
+        // Check that the sender is the owner of the token.
+        check_key_owner(sender_id.clone(), &key_info);
+
+        //only revoke if the approved account IDs for the token is not empty
+        if !key_info.approved_account_ids.is_empty() {
+            key_info.approved_account_ids.clear();
+            key_info.approval_expiries.clear();
             // Reinsert key info mapping to NFT and then add token ID mapping to public key
             drop.key_info_by_token_id.insert(&token_id, &key_info);
             self.drop_by_id.insert(&drop_id, &drop);
         }
+
+        self.internal_settle_owner_storage(&sender_id, &drop.funder_id, initial_storage);
     }
+
+    /// Charge the caller for any storage they took up managing their keys or refund them for any that was released.
+    /// Keys that sign for themselves have no account to charge so their storage is taken from (or given back to)
+    /// The balance of *funder_id*, the funder of the key's drop.
+    pub(crate) fn internal_settle_owner_storage(
+        &mut self,
+        sender_id: &AccountId,
+        funder_id: &AccountId,
+        initial_storage: u64,
+    ) {
+        if sender_id == &env::current_account_id() {
+            let final_storage = env::storage_usage();
+            if final_storage > initial_storage {
+                let storage_cost = (final_storage - initial_storage) as u128
+                    * env::storage_byte_cost().as_yoctonear();
+                self.internal_modify_user_balance(funder_id, storage_cost, true);
+            } else if final_storage < initial_storage {
+                let storage_refund = (initial_storage - final_storage) as u128
+                    * env::storage_byte_cost().as_yoctonear();
+                self.internal_modify_user_balance(funder_id, storage_refund, false);
+            }
+            return;
+        }
+
+        let refund_amount =
+            self.internal_settle_storage(initial_storage, env::attached_deposit().as_yoctonear());
+        if refund_amount > 0 {
+            Promise::new(sender_id.clone()).transfer(NearToken::from_yoctonear(refund_amount));
+        }
+    }
+}
+
+/// Check whether the approval for the given account has passed its expiry (if it has one)
+pub(crate) fn approval_is_expired(key_info: &InternalKeyInfo, account_id: &AccountId) -> bool {
+    key_info
+        .approval_expiries
+        .get(account_id)
+        .map(|expires_at| env::block_timestamp() >= *expires_at)
+        .unwrap_or(false)
 }
 
 /// Check that the sender is either the owner of the token or the current account (meaning they signed with the key).
@@ -143,3 +267,56 @@ pub(crate) fn check_key_owner(sender_id: AccountId, key_info: &InternalKeyInfo)
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::*;
+    use crate::*;
+
+    #[test]
+    fn approvals_signed_by_the_key_are_paid_for_by_the_funder() {
+        let mut contract = setup_contract();
+        create_drop(
+            &mut contract,
+            "approval_drop",
+            &[public_key(1)],
+            None,
+            json!([{ "uses": 1, "assets": [null] }]),
+            json!(null),
+        );
+        add_to_balance(&mut contract, &funder_id(), NearToken::from_near(1));
+        let initial_balance = contract.get_user_balance(funder_id()).0;
+
+        sign_with_key(&public_key(1));
+        contract.nft_approve(None, "market.near".parse().unwrap(), None, None);
+        assert!(contract.nft_is_approved(
+            "approval_drop:0".to_string(),
+            "market.near".parse().unwrap(),
+            None
+        ));
+        assert!(contract.get_user_balance(funder_id()).0 < initial_balance);
+
+        // Revoking the approval gives the storage back
+        sign_with_key(&public_key(1));
+        contract.nft_revoke(None, "market.near".parse().unwrap());
+        assert_eq!(contract.get_user_balance(funder_id()).0, initial_balance);
+    }
+
+    #[test]
+    #[should_panic(expected = "is less than required")]
+    fn approvals_signed_by_the_key_fail_if_the_funder_cannot_pay() {
+        let mut contract = setup_contract();
+        create_drop(
+            &mut contract,
+            "approval_drop",
+            &[public_key(1)],
+            None,
+            json!([{ "uses": 1, "assets": [null] }]),
+            json!(null),
+        );
+        assert_eq!(contract.get_user_balance(funder_id()).0, 0);
+
+        sign_with_key(&public_key(1));
+        contract.nft_approve(None, "market.near".parse().unwrap(), None, None);
+    }
+}
@@ -83,6 +83,10 @@ impl Keypom {
             if !key_info.approved_account_ids.contains_key(&sender_id) {
                 env::panic_str("Unauthorized");
             }
+            require!(
+                !approval_is_expired(&key_info, &sender_id),
+                "Approval has expired"
+            );
 
             // If they included an approval_id, check if the sender's actual approval_id is the same as the one included
             if let Some(enforced_approval_id) = approval_id {
@@ -102,6 +106,17 @@ impl Keypom {
             }
         }
 
+        // Make sure the drop allows this key to be transferred to the receiver
+        if let Some(restrictions) = drop
+            .config
+            .as_ref()
+            .and_then(|c| c.nft_keys_config.as_ref())
+            .and_then(|c| c.transfer_restrictions.as_ref())
+        {
+            restrictions
+                .assert_can_transfer(&key_info, receiver_id.as_ref().unwrap_or(&cur_account));
+        }
+
         // Remove token from old owner if there is one
         if let Some(owner) = key_info.owner_id.as_ref() {
             self.internal_remove_token_from_owner(owner, &token_id);
@@ -121,10 +136,15 @@ impl Keypom {
             owner_id: receiver_id.clone(),
             pub_key: new_public_key.clone(),
             approved_account_ids: Default::default(),
+            approval_expiries: Default::default(),
             remaining_uses: key_info.remaining_uses,
+            uses_consumed: key_info.uses_consumed,
             next_approval_id: key_info.next_approval_id,
             metadata: key_info.metadata,
+            token_metadata: key_info.token_metadata,
+            time_config: key_info.time_config,
             last_claimed: key_info.last_claimed,
+            last_transferred: env::block_timestamp(),
             pw_by_use: key_info.pw_by_use,
         };
 
@@ -143,65 +163,18 @@ impl Keypom {
         }
 
         // Construct the transfer log as per the events standard.
-        let event_logs = vec![
-            EventLog {
-                standard: NFT_STANDARD_NAME.to_string(),
-                version: NFT_METADATA_SPEC.to_string(),
-                event: EventLogVariant::NftTransfer(vec![NftTransferLog {
-                    authorized_id: authorized_id.clone(),
-                    old_owner_id: old_owner.to_string(),
-                    new_owner_id: receiver_id
-                        .as_ref()
-                        .unwrap_or(&env::current_account_id())
-                        .to_string(),
-                    token_ids: vec![token_id.to_string()],
-                    memo: None,
-                }]),
-            },
-            EventLog {
-                standard: KEYPOM_STANDARD_NAME.to_string(),
-                version: KEYPOM_STANDARD_VERSION.to_string(),
-                event: EventLogVariant::KeyTransfer(TransferKeyLog {
-                    authorized_id,
-                    old_owner_id: old_owner.to_string(),
-                    new_owner_id: receiver_id
-                        .as_ref()
-                        .unwrap_or(&env::current_account_id())
-                        .to_string(),
-                    old_public_key: (&old_pub_key).into(),
-                    new_public_key: (&new_public_key).into(),
-                    drop_id,
-                    token_id,
-                }),
-            },
-        ];
-
-        // Delete the original key
-        Promise::new(env::current_account_id()).delete_key(old_pub_key);
-
-        // Get the allowance that should be transferred to the new key
-        let mut total_cost_for_key: Balance = 0;
-        let mut total_allowance_for_key: Balance = drop
-            .config
-            .as_ref()
-            .and_then(|config| config.extra_allowance_per_key)
-            .unwrap_or(U128(0))
-            .0;
-        get_total_costs_for_key(
-            &mut total_cost_for_key,
-            &mut total_allowance_for_key,
-            key_info.remaining_uses,
-            &drop.asset_by_id,
-            &drop.asset_data_for_uses,
+        let event_logs = key_transfer_logs(
+            authorized_id,
+            old_owner,
+            receiver_id.as_ref().unwrap_or(&cur_account),
+            &old_pub_key,
+            &new_public_key,
+            drop_id,
+            token_id,
         );
 
-        // Add the new key with the corresponding allowance
-        Promise::new(env::current_account_id()).add_access_key(
-            new_public_key.clone(),
-            NearToken::from_yoctonear(total_allowance_for_key),
-            env::current_account_id(),
-            ACCESS_KEY_METHOD_NAMES.to_string(),
-        );
+        // Swap the original key out for the new one
+        rotate_access_key(&drop, key_info.remaining_uses, old_pub_key, new_public_key);
 
         // Log the transfer events
         log_events(event_logs);
@@ -209,3 +182,82 @@ impl Keypom {
         old_owner.clone()
     }
 }
+
+/// Delete `old_public_key` from the contract and add `new_public_key` in its place with the allowance
+/// A key from the drop needs for its remaining uses. Both actions happen in the same batch so the
+/// Same public key can be passed in to simply reset its allowance
+pub(crate) fn rotate_access_key(
+    drop: &InternalDrop,
+    remaining_uses: UseNumber,
+    old_public_key: PublicKey,
+    new_public_key: PublicKey,
+) {
+    let allowance = get_allowance_for_key(drop, remaining_uses);
+
+    Promise::new(env::current_account_id())
+        .delete_key(old_public_key)
+        .add_access_key(
+            new_public_key,
+            NearToken::from_yoctonear(allowance),
+            env::current_account_id(),
+            ACCESS_KEY_METHOD_NAMES.to_string(),
+        );
+}
+
+/// Get the allowance that a key in the drop needs for the given number of remaining uses
+pub(crate) fn get_allowance_for_key(drop: &InternalDrop, remaining_uses: UseNumber) -> Balance {
+    let mut total_cost_for_key: Balance = 0;
+    let mut total_allowance_for_key: Balance = drop
+        .config
+        .as_ref()
+        .and_then(|config| config.extra_allowance_per_key)
+        .unwrap_or(U128(0))
+        .0;
+    get_total_costs_for_key(
+        &mut total_cost_for_key,
+        &mut total_allowance_for_key,
+        remaining_uses,
+        &drop.asset_by_id,
+        &drop.asset_data_for_uses,
+    );
+
+    total_allowance_for_key
+}
+
+/// Construct the NFT transfer and key transfer logs as per the events standard
+pub(crate) fn key_transfer_logs(
+    authorized_id: Option<String>,
+    old_owner: &AccountId,
+    new_owner: &AccountId,
+    old_public_key: &PublicKey,
+    new_public_key: &PublicKey,
+    drop_id: DropId,
+    token_id: TokenId,
+) -> Vec<EventLog> {
+    vec![
+        EventLog {
+            standard: NFT_STANDARD_NAME.to_string(),
+            version: NFT_METADATA_SPEC.to_string(),
+            event: EventLogVariant::NftTransfer(vec![NftTransferLog {
+                authorized_id: authorized_id.clone(),
+                old_owner_id: old_owner.to_string(),
+                new_owner_id: new_owner.to_string(),
+                token_ids: vec![token_id.to_string()],
+                memo: None,
+            }]),
+        },
+        EventLog {
+            standard: KEYPOM_STANDARD_NAME.to_string(),
+            version: KEYPOM_STANDARD_VERSION.to_string(),
+            event: EventLogVariant::KeyTransfer(TransferKeyLog {
+                authorized_id,
+                old_owner_id: old_owner.to_string(),
+                new_owner_id: new_owner.to_string(),
+                old_public_key: old_public_key.into(),
+                new_public_key: new_public_key.into(),
+                drop_id,
+                token_id,
+            }),
+        },
+    ]
+}
@@ -0,0 +1,329 @@
+use crate::*;
+
+#[near_bindgen]
+impl Keypom {
+    /// Split some of the remaining uses on an NFT key into new keys in the same drop.
+    /// Every public key in *new_public_keys* becomes a new key owned by the caller with *uses_each* uses
+    /// And the original key keeps whatever is left. Since a key's current use is based on how many uses it
+    /// Has remaining, every key ends up claiming the last uses of the drop. The new keys count the original
+    /// Key's consumed uses as their own so they follow the same schedule.
+    /// Any extra storage is paid for by the owner with their attached deposit or balance.
+    #[payable]
+    pub fn split_key(
+        &mut self,
+        token_id: TokenId,
+        new_public_keys: Vec<PublicKey>,
+        uses_each: UseNumber,
+    ) -> bool {
+        self.assert_no_global_freeze();
+        let initial_storage = env::storage_usage();
+        let owner_id = env::predecessor_account_id();
+
+        let drop_id = parse_token_id(&token_id).unwrap().0;
+        let mut drop = self.drop_by_id.get(&drop_id).expect("Drop not found");
+        let mut key_info = drop
+            .key_info_by_token_id
+            .get(&token_id)
+            .expect("Key info not found");
+        require!(
+            key_info.owner_id.as_ref() == Some(&owner_id),
+            "Only the key owner can split keys"
+        );
+        require!(
+            key_info.pw_by_use.is_none(),
+            "Keys with passwords cannot be split"
+        );
+        require!(
+            !new_public_keys.is_empty() && new_public_keys.len() <= 100 && uses_each > 0,
+            "Must split at least one use into between 1 and 100 keys"
+        );
+
+        // The original key must keep at least one use
+        let uses_to_split = new_public_keys.len() as UseNumber * uses_each;
+        require!(
+            uses_to_split < key_info.remaining_uses,
+            "Key does not have enough uses to split"
+        );
+        drop.update_tokens_required(
+            1,
+            key_info.remaining_uses,
+            key_info.remaining_uses - uses_to_split,
+        );
+        key_info.remaining_uses -= uses_to_split;
+        drop.key_info_by_token_id.insert(&token_id, &key_info);
+
+        // The original key's allowance only needs to cover the uses it has left
+        rotate_access_key(
+            &drop,
+            key_info.remaining_uses,
+            key_info.pub_key.clone(),
+            key_info.pub_key.clone(),
+        );
+
+        // Mint the new keys with the uses that were split off
+        let key_data: Vec<ExtKeyData> = new_public_keys
+            .into_iter()
+            .map(|public_key| ExtKeyData {
+                public_key,
+                password_by_use: None,
+                metadata: key_info.metadata.clone(),
+                key_owner: Some(owner_id.clone()),
+                token_metadata: key_info.token_metadata.clone(),
+                // Copied over below since the original key's start may have already passed
+                time_config: None,
+            })
+            .collect();
+        let allowance_per_key = get_allowance_for_key(&drop, uses_each);
+        let first_key_id = drop.next_key_id;
+        let mut event_logs = Vec::new();
+        self.internal_add_keys_to_account(
+            &mut drop.next_key_id,
+            &mut drop.key_info_by_token_id,
+            &mut event_logs,
+            &drop_id,
+            uses_each,
+            &key_data,
+            allowance_per_key,
+        );
+        drop.update_tokens_required(key_data.len() as u128, 0, uses_each);
+
+        // The new keys follow the original key's time config and pick up where it is in its schedule and intervals
+        for key_id in first_key_id..drop.next_key_id {
+            let new_token_id = format!("{}:{}", drop_id, key_id);
+            let mut new_key_info = drop
+                .key_info_by_token_id
+                .get(&new_token_id)
+                .expect("Key info not found");
+            new_key_info.time_config = key_info.time_config.clone();
+            new_key_info.uses_consumed = key_info.uses_consumed;
+            drop.key_info_by_token_id
+                .insert(&new_token_id, &new_key_info);
+        }
+        self.drop_by_id.insert(&drop_id, &drop);
+
+        self.internal_settle_owner_storage(&owner_id, &drop.funder_id, initial_storage);
+
+        event_logs.push(EventLog {
+            standard: KEYPOM_STANDARD_NAME.to_string(),
+            version: KEYPOM_STANDARD_VERSION.to_string(),
+            event: EventLogVariant::KeyUsesTransfer(
+                (first_key_id..drop.next_key_id)
+                    .map(|key_id| KeyUsesTransferLog {
+                        owner_id: owner_id.to_string(),
+                        drop_id: drop_id.clone(),
+                        old_token_id: token_id.clone(),
+                        new_token_id: format!("{}:{}", drop_id, key_id),
+                        uses: uses_each,
+                    })
+                    .collect(),
+            ),
+        });
+        log_events(event_logs);
+        true
+    }
+
+    /// Merge NFT keys from the same drop and owner into the first key in *token_ids*.
+    /// The remaining uses of every other key are added to the first key and the other keys are burned.
+    /// The burned keys and the uses moved out of them are logged as events.
+    /// Any storage released is refunded to the owner.
+    #[payable]
+    pub fn merge_keys(&mut self, token_ids: Vec<TokenId>) -> bool {
+        self.assert_no_global_freeze();
+        let initial_storage = env::storage_usage();
+        let owner_id = env::predecessor_account_id();
+
+        require!(
+            token_ids.len() > 1 && token_ids.len() <= 100,
+            "Must merge between 2 and 100 keys"
+        );
+        let target_token_id = &token_ids[0];
+        let drop_id = parse_token_id(target_token_id).unwrap().0;
+        let mut drop = self.drop_by_id.get(&drop_id).expect("Drop not found");
+        let mut target_key_info = drop
+            .key_info_by_token_id
+            .get(target_token_id)
+            .expect("Key info not found");
+        require!(
+            target_key_info.owner_id.as_ref() == Some(&owner_id),
+            "Only the key owner can merge keys"
+        );
+        require!(
+            target_key_info.pw_by_use.is_none(),
+            "Keys with passwords cannot be merged"
+        );
+
+        let mut nft_burn_logs = Vec::new();
+        let mut delete_key_logs = Vec::new();
+        let mut uses_transfer_logs = Vec::new();
+        for token_id in token_ids.iter().skip(1) {
+            require!(
+                token_id != target_token_id,
+                "Cannot merge a key into itself"
+            );
+            require!(
+                parse_token_id(token_id).unwrap().0 == drop_id,
+                "Keys must be from the same drop"
+            );
+
+            // Removing the key info means the same key can't be merged twice
+            let key_info = drop
+                .key_info_by_token_id
+                .remove(token_id)
+                .expect("Key info not found");
+            require!(
+                key_info.owner_id.as_ref() == Some(&owner_id),
+                "Keys must have the same owner"
+            );
+            require!(
+                key_info.pw_by_use.is_none(),
+                "Keys with passwords cannot be merged"
+            );
+            drop.update_tokens_required(1, key_info.remaining_uses, 0);
+            drop.update_tokens_required(
+                1,
+                target_key_info.remaining_uses,
+                target_key_info.remaining_uses + key_info.remaining_uses,
+            );
+            target_key_info.remaining_uses += key_info.remaining_uses;
+            // The merged key continues from whichever key is furthest along in its schedule
+            target_key_info.uses_consumed =
+                target_key_info.uses_consumed.max(key_info.uses_consumed);
+
+            uses_transfer_logs.push(KeyUsesTransferLog {
+                owner_id: owner_id.to_string(),
+                drop_id: drop_id.clone(),
+                old_token_id: token_id.clone(),
+                new_token_id: target_token_id.clone(),
+                uses: key_info.remaining_uses,
+            });
+
+            // Burn the key
+            self.internal_remove_token_from_owner(&owner_id, token_id);
+            self.token_id_by_pk.remove(&key_info.pub_key);
+            Promise::new(env::current_account_id()).delete_key(key_info.pub_key.clone());
+            add_delete_key_logs(
+                &mut nft_burn_logs,
+                &mut delete_key_logs,
+                &key_info.owner_id,
+                &drop_id,
+                &key_info.pub_key,
+                token_id,
+            );
+        }
+        require!(
+            target_key_info.remaining_uses <= drop.max_key_uses,
+            "Merged key cannot have more uses than the drop allows"
+        );
+        drop.key_info_by_token_id
+            .insert(target_token_id, &target_key_info);
+
+        // The merged key's allowance needs to cover all the uses it now has
+        rotate_access_key(
+            &drop,
+            target_key_info.remaining_uses,
+            target_key_info.pub_key.clone(),
+            target_key_info.pub_key.clone(),
+        );
+        self.drop_by_id.insert(&drop_id, &drop);
+
+        self.internal_settle_owner_storage(&owner_id, &drop.funder_id, initial_storage);
+
+        log_events(vec![
+            EventLog {
+                standard: NFT_STANDARD_NAME.to_string(),
+                version: NFT_METADATA_SPEC.to_string(),
+                event: EventLogVariant::NftBurn(nft_burn_logs),
+            },
+            EventLog {
+                standard: KEYPOM_STANDARD_NAME.to_string(),
+                version: KEYPOM_STANDARD_VERSION.to_string(),
+                event: EventLogVariant::DeleteKey(delete_key_logs),
+            },
+            EventLog {
+                standard: KEYPOM_STANDARD_NAME.to_string(),
+                version: KEYPOM_STANDARD_VERSION.to_string(),
+                event: EventLogVariant::KeyUsesTransfer(uses_transfer_logs),
+            },
+        ]);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use near_sdk::testing_env;
+
+    use crate::drop_claiming::schedules::NANOS_PER_DAY;
+    use crate::test_utils::*;
+    use crate::*;
+
+    fn holder_id() -> AccountId {
+        "holder.near".parse().unwrap()
+    }
+
+    /// Claim a use of the key for *public_key* during the given week of a weekly schedule
+    fn claim_in_week(contract: &mut Keypom, public_key: &PublicKey, week: u64) {
+        testing_env!(context(&keypom_id())
+            .signer_account_pk(public_key.clone())
+            .block_timestamp(week * 7 * NANOS_PER_DAY + 1)
+            .build());
+        contract.claim(holder_id(), None, None, None, None);
+    }
+
+    /// Create a key with 4 uses on a weekly schedule (where missed weeks accumulate), claim it twice
+    /// During the second week and then split one of its remaining uses into a new key
+    fn split_scheduled_key() -> Keypom {
+        let mut contract = setup_contract();
+        create_drop(
+            &mut contract,
+            "schedule_drop",
+            &[public_key(1)],
+            Some(holder_id()),
+            json!([{
+                "uses": 4,
+                "assets": [null],
+                "config": {
+                    "time": {
+                        "start": 0,
+                        "schedule": { "period": { "weekly": { "day_of_week": 3 } }, "missed_periods": "accumulate" }
+                    }
+                }
+            }]),
+            json!(null),
+        );
+        claim_in_week(&mut contract, &public_key(1), 1);
+        claim_in_week(&mut contract, &public_key(1), 1);
+
+        testing_env!(context(&holder_id())
+            .attached_deposit(NearToken::from_near(1))
+            .block_timestamp(7 * NANOS_PER_DAY + 1)
+            .build());
+        contract.split_key("schedule_drop:0".to_string(), vec![public_key(2)], 1);
+        contract
+    }
+
+    #[test]
+    fn split_keys_keep_their_place_in_the_schedule() {
+        let mut contract = split_scheduled_key();
+        let drop = contract
+            .drop_by_id
+            .get(&"schedule_drop".to_string())
+            .unwrap();
+        let new_key_info = drop
+            .key_info_by_token_id
+            .get(&"schedule_drop:1".to_string())
+            .unwrap();
+        assert_eq!(new_key_info.uses_consumed, 2);
+
+        // Both keys have claimed 2 uses so they can each claim once the third week begins
+        claim_in_week(&mut contract, &public_key(1), 2);
+        claim_in_week(&mut contract, &public_key(2), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "has been claimed 2 times and only 2 periods have begun")]
+    fn split_keys_cannot_claim_uses_the_original_key_already_claimed() {
+        let mut contract = split_scheduled_key();
+        claim_in_week(&mut contract, &public_key(2), 1);
+    }
+}
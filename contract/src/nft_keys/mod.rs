@@ -4,5 +4,9 @@ mod metadata;
 mod nft_core;
 mod royalty;
 mod approval;
+mod key_splitting;
 
-pub use metadata::*;
\ No newline at end of file
+pub(crate) use approval::*;
+pub(crate) use internal::*;
+pub use metadata::*;
+pub(crate) use royalty::*;
\ No newline at end of file
@@ -1,3 +1,5 @@
+use near_sdk::PromiseResult;
+
 use crate::*;
 
 #[near_bindgen]
@@ -28,6 +30,156 @@ impl Keypom {
         self.internal_transfer(sender_id, receiver_id, token_id, approval_id, memo);
     }
 
+    /// Transfers an NFT key to a contract and notifies it by calling `nft_on_transfer` with the given *msg*.
+    /// Just like `nft_transfer`, the *memo* field is the new public key that the token will be associated with.
+    /// If the receiver returns `true`, the key is given back to the previous owner along with its previous public key.
+    #[payable]
+    pub fn nft_transfer_call(
+        &mut self,
+        receiver_id: AccountId,
+        token_id: Option<TokenId>,
+        approval_id: Option<u64>,
+        memo: PublicKey,
+        msg: String,
+    ) -> PromiseOrValue<bool> {
+        self.assert_no_global_freeze();
+
+        let sender_id = env::predecessor_account_id();
+        let sender_pk = env::signer_account_pk();
+        require!(
+            env::prepaid_gas().as_gas()
+                >= GAS_FOR_NFT_ON_TRANSFER.as_gas() + GAS_FOR_RESOLVE_TRANSFER.as_gas(),
+            "Not enough gas attached for nft_transfer_call"
+        );
+
+        // Token ID is either from sender PK or passed in
+        let token_id = self
+            .token_id_by_pk
+            .get(&sender_pk)
+            .unwrap_or_else(|| token_id.expect("Token ID not provided"));
+
+        // Keep track of the previous public key and approvals in case the transfer needs to be reverted
+        let drop_id = parse_token_id(&token_id).unwrap().0;
+        let key_info = self
+            .drop_by_id
+            .get(&drop_id)
+            .and_then(|d| d.key_info_by_token_id.get(&token_id))
+            .expect("Key info not found");
+
+        let previous_owner_id = self.internal_transfer(
+            sender_id.clone(),
+            Some(receiver_id.clone()),
+            token_id.clone(),
+            approval_id,
+            memo.clone(),
+        );
+
+        Promise::new(receiver_id.clone())
+            .function_call(
+                "nft_on_transfer".to_string(),
+                json!({ "sender_id": sender_id, "previous_owner_id": previous_owner_id, "token_id": token_id, "msg": msg }).to_string().into(),
+                NearToken::from_yoctonear(0),
+                GAS_FOR_NFT_ON_TRANSFER,
+            )
+            .then(
+                Self::ext(env::current_account_id())
+                    .with_static_gas(GAS_FOR_RESOLVE_TRANSFER)
+                    .nft_resolve_transfer(
+                        previous_owner_id,
+                        receiver_id,
+                        token_id,
+                        KeyApprovals {
+                            approved_account_ids: key_info.approved_account_ids,
+                            approval_expiries: key_info.approval_expiries,
+                        },
+                        key_info.pub_key,
+                        memo,
+                    ),
+            )
+            .into()
+    }
+
+    /// Resolve an `nft_transfer_call`. If the receiver asked for the key to be returned (and still owns it
+    /// with the public key it was sent with), the key is given back to the previous owner and public key.
+    /// Returns true if the key was successfully transferred to the receiver.
+    #[private]
+    pub fn nft_resolve_transfer(
+        &mut self,
+        owner_id: AccountId,
+        receiver_id: AccountId,
+        token_id: TokenId,
+        previous_approvals: KeyApprovals,
+        previous_public_key: PublicKey,
+        new_public_key: PublicKey,
+    ) -> bool {
+        // Whether the receiver wants to return the key back to the sender. Anything other than `false` means yes
+        let should_revert = match env::promise_result(0) {
+            PromiseResult::Successful(value) => {
+                near_sdk::serde_json::from_slice::<bool>(&value).unwrap_or(true)
+            }
+            PromiseResult::Failed => true,
+        };
+        if !should_revert {
+            return true;
+        }
+
+        // The key may have been claimed, deleted or transferred again in the meantime
+        let drop_id = parse_token_id(&token_id).unwrap().0;
+        let mut drop = match self.drop_by_id.get(&drop_id) {
+            Some(drop) => drop,
+            None => return true,
+        };
+        let mut key_info = match drop.key_info_by_token_id.get(&token_id) {
+            Some(key_info) => key_info,
+            None => return true,
+        };
+        if key_info.owner_id.as_ref() != Some(&receiver_id)
+            || key_info.pub_key != new_public_key
+            || self.token_id_by_pk.get(&new_public_key).as_ref() != Some(&token_id)
+            || self.token_id_by_pk.get(&previous_public_key).is_some()
+        {
+            return true;
+        }
+
+        // Give the token back to the previous owner. Keys owned by the contract have no owner
+        self.internal_remove_token_from_owner(&receiver_id, &token_id);
+        let cur_account = env::current_account_id();
+        if owner_id != cur_account {
+            self.internal_add_token_to_owner(&owner_id, &token_id);
+            key_info.owner_id = Some(owner_id.clone());
+        } else {
+            key_info.owner_id = None;
+        }
+
+        // Swap back to the previous public key and approvals
+        self.token_id_by_pk.remove(&new_public_key);
+        self.token_id_by_pk.insert(&previous_public_key, &token_id);
+        key_info.pub_key = previous_public_key.clone();
+        key_info.approved_account_ids = previous_approvals.approved_account_ids;
+        key_info.approval_expiries = previous_approvals.approval_expiries;
+        drop.key_info_by_token_id.insert(&token_id, &key_info);
+        self.drop_by_id.insert(&drop_id, &drop);
+
+        rotate_access_key(
+            &drop,
+            key_info.remaining_uses,
+            new_public_key.clone(),
+            previous_public_key.clone(),
+        );
+
+        log_events(key_transfer_logs(
+            None,
+            &receiver_id,
+            &owner_id,
+            &new_public_key,
+            &previous_public_key,
+            drop_id,
+            token_id,
+        ));
+
+        false
+    }
+
     /// Get the token object info for a specific token ID
     pub fn nft_token(&self, token_id: TokenId) -> Option<ExtNFTKey> {
         let drop_id = parse_token_id(&token_id).unwrap().0;
@@ -36,19 +188,47 @@ impl Keypom {
             let NFTKeyConfigurations {
                 token_metadata,
                 royalties,
+                contract_metadata,
+                transfer_restrictions: _,
+                max_resale_price: _,
+                funder_revenue_share: _,
+                approved_marketplaces: _,
             } = drop
                 .config
                 .and_then(|c| c.nft_keys_config)
                 .unwrap_or(NFTKeyConfigurations {
                     token_metadata: None,
                     royalties: None,
+                    contract_metadata: None,
+                    transfer_restrictions: None,
+                    max_resale_price: None,
+                    funder_revenue_share: None,
+                    approved_marketplaces: None,
                 });
 
             if let Some(key_info) = drop.key_info_by_token_id.get(&token_id) {
+                // Key level metadata takes precedence over the drop's template
+                let metadata = key_info.token_metadata.clone().or_else(|| {
+                    token_metadata.map(|m| m.render_for_key(&drop_id, &token_id, &key_info))
+                });
+                // Drops with their own collection metadata show it on every token since wallets won't
+                // Look it up through `nft_metadata_for_drop`
+                let metadata = match contract_metadata {
+                    Some(collection) => Some(
+                        metadata
+                            .unwrap_or_default()
+                            .with_collection_metadata(&collection),
+                    ),
+                    None => metadata,
+                };
+
                 return Some(ExtNFTKey {
+                    owner_id: key_info
+                        .owner_id
+                        .clone()
+                        .unwrap_or(env::current_account_id()),
                     token_id,
-                    owner_id: key_info.owner_id.unwrap_or(env::current_account_id()),
-                    metadata: token_metadata.unwrap_or(TokenMetadata {
+                    metadata: metadata.unwrap_or(TokenMetadata {
                         title: Some(String::from("Keypom Access Key")),
                         description: Some(String::from("Keypom is pretty lit")),
                         media: Some(String::from(
@@ -70,6 +250,56 @@ impl Keypom {
             }
         }
 
-        return None;
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::*;
+    use crate::*;
+
+    #[test]
+    fn tokens_show_the_drop_collection_metadata() {
+        let mut contract = setup_contract();
+        create_drop(
+            &mut contract,
+            "event_drop",
+            &[public_key(1)],
+            Some(funder_id()),
+            json!([{ "uses": 1, "assets": [null] }]),
+            json!({
+                "nft_keys_config": {
+                    "token_metadata": { "description": "Entry to {drop_id}", "media": "ticket.png" },
+                    "contract_metadata": {
+                        "spec": "nft-1.0.0",
+                        "name": "Event Tickets",
+                        "symbol": "TIX",
+                        "icon": "data:image/svg+xml,<svg/>",
+                        "base_uri": "https://event.example/",
+                        "reference": "collection.json",
+                        "reference_hash": null
+                    }
+                }
+            }),
+        );
+
+        let token = contract.nft_token("event_drop:0".to_string()).unwrap();
+        assert_eq!(token.metadata.title.as_deref(), Some("Event Tickets"));
+        assert_eq!(
+            token.metadata.description.as_deref(),
+            Some("Entry to event_drop")
+        );
+        assert_eq!(
+            token.metadata.media.as_deref(),
+            Some("https://event.example/ticket.png")
+        );
+        assert_eq!(
+            token.metadata.reference.as_deref(),
+            Some("https://event.example/collection.json")
+        );
+
+        let tokens = contract.nft_tokens_for_owner(funder_id(), None, None);
+        assert_eq!(tokens[0].metadata.title.as_deref(), Some("Event Tickets"));
     }
 }
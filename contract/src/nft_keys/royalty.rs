@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use crate::*;
 
 /// The maximum number of people that can be paid out to in a single transaction
-const MAX_LEN_PAYOUT: u32 = 10;
+pub(crate) const MAX_LEN_PAYOUT: u32 = 10;
 
 #[near_bindgen]
 impl Keypom {
@@ -36,14 +36,24 @@ impl Keypom {
         let drop_id = parse_token_id(&token_id).unwrap().0;
         let drop = self.drop_by_id.get(&drop_id).expect("Drop not found");
 
-        // Get royalties from key info
-        let default_royalty = &HashMap::new();
-        let nft_royalty = drop
+        // Make sure the key isn't being resold above the drop's price cap
+        if let Some(max_resale_price) = drop
             .config
             .as_ref()
             .and_then(|c| c.nft_keys_config.as_ref())
-            .and_then(|c| c.royalties.as_ref())
-            .unwrap_or(default_royalty);
+            .and_then(|c| c.max_resale_price)
+        {
+            require!(
+                balance.0 <= max_resale_price.0,
+                format!(
+                    "Resale price {} exceeds the maximum of {}",
+                    balance.0, max_resale_price.0
+                )
+            );
+        }
+
+        // Get royalties (including the funder's cut) from the drop
+        let nft_royalty = get_payout_royalties(&drop);
 
         // Perform the transfer and then calculate payouts
         let old_owner_id =
@@ -51,7 +61,7 @@ impl Keypom {
 
         calculate_payouts(
             old_owner_id,
-            nft_royalty.clone(),
+            nft_royalty,
             u128::from(balance),
             max_len_payout.unwrap_or(MAX_LEN_PAYOUT),
         )
@@ -68,13 +78,7 @@ impl Keypom {
         let drop_id = parse_token_id(&token_id).unwrap().0;
 
         let drop = self.drop_by_id.get(&drop_id).expect("Drop not found");
-        let default_royalty = &HashMap::new();
-        let nft_royalty = drop
-            .config
-            .as_ref()
-            .and_then(|c| c.nft_keys_config.as_ref())
-            .and_then(|c| c.royalties.as_ref())
-            .unwrap_or(default_royalty);
+        let nft_royalty = get_payout_royalties(&drop);
         let key_info = drop
             .key_info_by_token_id
             .get(&token_id)
@@ -82,13 +86,53 @@ impl Keypom {
 
         calculate_payouts(
             key_info.owner_id.unwrap_or(env::current_account_id()),
-            nft_royalty.clone(),
+            nft_royalty,
             u128::from(balance),
             max_len_payout.unwrap_or(MAX_LEN_PAYOUT),
         )
     }
 }
 
+/// Get the royalties that should be paid out for a key in the drop. This is the drop's royalties
+/// Plus the funder's revenue share (if any)
+pub(crate) fn get_payout_royalties(drop: &InternalDrop) -> HashMap<AccountId, u32> {
+    let nft_keys_config = drop
+        .config
+        .as_ref()
+        .and_then(|c| c.nft_keys_config.as_ref());
+    let mut royalties = nft_keys_config
+        .and_then(|c| c.royalties.clone())
+        .unwrap_or_default();
+
+    if let Some(share) = nft_keys_config.and_then(|c| c.funder_revenue_share) {
+        *royalties.entry(drop.funder_id.clone()).or_insert(0) += share;
+    }
+
+    royalties
+}
+
+/// Make sure the royalties and funder revenue share for a drop can always be paid out.
+/// The total can't exceed 100% and there must be room for the owner in the payout.
+pub(crate) fn assert_valid_nft_keys_config(config: &NFTKeyConfigurations, funder_id: &AccountId) {
+    let mut royalties = config.royalties.clone().unwrap_or_default();
+    if let Some(share) = config.funder_revenue_share {
+        *royalties.entry(funder_id.clone()).or_insert(0) += share;
+    }
+
+    let total_royalties: u32 = royalties.values().sum();
+    require!(
+        total_royalties <= 10_000,
+        "Royalties cannot total more than 10000 basis points"
+    );
+    require!(
+        (royalties.len() as u32) < MAX_LEN_PAYOUT,
+        format!(
+            "Cannot have more than {} royalty receivers",
+            MAX_LEN_PAYOUT - 1
+        )
+    );
+}
+
 /// Helper function to convert a royalty percentage and amount to a payout
 pub(crate) fn calculate_payouts(
     owner_id: AccountId,
@@ -30,6 +30,12 @@ impl Keypom {
         );
     }
 
+    /// Set the collection level metadata for the NFT keys on the contract
+    pub fn set_contract_metadata(&mut self, contract_metadata: NFTContractMetadata) {
+        self.assert_owner();
+        self.nft_contract_metadata = contract_metadata;
+    }
+
     /// Set the contract to be frozen thus not allowing any drops to be created or keys added
     pub fn freeze_contract(&mut self) {
         self.assert_owner();
@@ -0,0 +1,191 @@
+use crate::*;
+
+#[near_bindgen]
+impl Keypom {
+    /// Allow *spender_id* to draw up to *total_limit* from the predecessor's balance when creating drops or adding keys
+    /// On the predecessor's behalf. If *period_limit* and *period_length* are passed in, the spender can also only draw
+    /// Up to *period_limit* every *period_length* nanoseconds. Granting an allowance that already exists updates its
+    /// Limits but keeps track of what was already spent.
+    /// Any storage is paid for by the funder with their attached deposit or balance.
+    #[payable]
+    pub fn grant_spending_allowance(
+        &mut self,
+        spender_id: AccountId,
+        total_limit: U128,
+        period_limit: Option<U128>,
+        period_length: Option<u64>,
+    ) -> bool {
+        self.assert_no_global_freeze();
+        let initial_storage = env::storage_usage();
+        let funder_id = env::predecessor_account_id();
+
+        require!(
+            spender_id != funder_id,
+            "Cannot grant a spending allowance to yourself"
+        );
+        require!(
+            period_limit.is_some() == period_length.is_some(),
+            "Must specify both a period limit and period length or neither"
+        );
+        require!(
+            period_length.unwrap_or(1) > 0,
+            "Period length must be greater than 0"
+        );
+
+        let mut allowances = self
+            .spending_allowances_by_funder
+            .get(&funder_id)
+            .unwrap_or_else(|| {
+                UnorderedMap::new(StorageKeys::SpendingAllowancesByFunderInner {
+                    account_id_hash: hash_string(&funder_id.to_string()),
+                })
+            });
+        let allowance = match allowances.get(&spender_id) {
+            Some(existing) => InternalSpendingAllowance {
+                total_limit: total_limit.0,
+                period_limit: period_limit.map(|l| l.0),
+                period_length,
+                ..existing
+            },
+            None => InternalSpendingAllowance {
+                total_limit: total_limit.0,
+                total_spent: 0,
+                period_limit: period_limit.map(|l| l.0),
+                period_length,
+                period_start: env::block_timestamp(),
+                period_spent: 0,
+            },
+        };
+        allowances.insert(&spender_id, &allowance);
+        self.spending_allowances_by_funder
+            .insert(&funder_id, &allowances);
+
+        let refund_amount =
+            self.internal_settle_storage(initial_storage, env::attached_deposit().as_yoctonear());
+        if refund_amount > 0 {
+            Promise::new(funder_id.clone()).transfer(NearToken::from_yoctonear(refund_amount));
+        }
+
+        log_events(vec![EventLog {
+            standard: KEYPOM_STANDARD_NAME.to_string(),
+            version: KEYPOM_STANDARD_VERSION.to_string(),
+            event: EventLogVariant::AllowanceGrant(AllowanceGrantLog {
+                funder_id: funder_id.to_string(),
+                spender_id: spender_id.to_string(),
+                total_limit: total_limit.0.to_string(),
+                period_limit: period_limit.map(|l| l.0.to_string()),
+                period_length,
+            }),
+        }]);
+        true
+    }
+
+    /// Revoke the spending allowance that the predecessor granted *spender_id*.
+    /// Any storage released is refunded to the funder.
+    pub fn revoke_spending_allowance(&mut self, spender_id: AccountId) -> bool {
+        let initial_storage = env::storage_usage();
+        let funder_id = env::predecessor_account_id();
+
+        let mut allowances = self
+            .spending_allowances_by_funder
+            .get(&funder_id)
+            .expect("No spending allowances found");
+        require!(
+            allowances.remove(&spender_id).is_some(),
+            "No spending allowance found for spender"
+        );
+        if allowances.is_empty() {
+            self.spending_allowances_by_funder.remove(&funder_id);
+        } else {
+            self.spending_allowances_by_funder
+                .insert(&funder_id, &allowances);
+        }
+
+        let refund_amount = self.internal_settle_storage(initial_storage, 0);
+        if refund_amount > 0 {
+            Promise::new(funder_id.clone()).transfer(NearToken::from_yoctonear(refund_amount));
+        }
+
+        log_events(vec![EventLog {
+            standard: KEYPOM_STANDARD_NAME.to_string(),
+            version: KEYPOM_STANDARD_VERSION.to_string(),
+            event: EventLogVariant::AllowanceRevoke(AllowanceRevokeLog {
+                funder_id: funder_id.to_string(),
+                spender_id: spender_id.to_string(),
+            }),
+        }]);
+        true
+    }
+
+    /// Check whether *spender_id* has been granted a spending allowance by *funder_id*
+    pub(crate) fn has_spending_allowance(
+        &self,
+        funder_id: &AccountId,
+        spender_id: &AccountId,
+    ) -> bool {
+        self.spending_allowances_by_funder
+            .get(funder_id)
+            .map(|allowances| allowances.get(spender_id).is_some())
+            .unwrap_or(false)
+    }
+
+    /// Record that *spender_id* drew *amount* from the funder's balance for *drop_id*.
+    /// Panics if the spender doesn't have an allowance or the amount would go over any of its limits
+    pub(crate) fn internal_spend_allowance(
+        &mut self,
+        funder_id: &AccountId,
+        spender_id: &AccountId,
+        amount: Balance,
+        drop_id: &DropId,
+    ) -> EventLog {
+        let mut allowances = self
+            .spending_allowances_by_funder
+            .get(funder_id)
+            .expect("No spending allowance found for spender");
+        let mut allowance = allowances
+            .get(spender_id)
+            .expect("No spending allowance found for spender");
+
+        // Start a new period if the current one has ended
+        if let Some(period_length) = allowance.period_length {
+            let elapsed = env::block_timestamp() - allowance.period_start;
+            if elapsed >= period_length {
+                allowance.period_start += elapsed / period_length * period_length;
+                allowance.period_spent = 0;
+            }
+        }
+
+        allowance.total_spent += amount;
+        require!(
+            allowance.total_spent <= allowance.total_limit,
+            format!(
+                "Spending allowance exceeded. Limit {} spent {}",
+                allowance.total_limit, allowance.total_spent
+            )
+        );
+        allowance.period_spent += amount;
+        if let Some(period_limit) = allowance.period_limit {
+            require!(
+                allowance.period_spent <= period_limit,
+                format!(
+                    "Spending allowance exceeded for this period. Limit {} spent {}",
+                    period_limit, allowance.period_spent
+                )
+            );
+        }
+
+        allowances.insert(spender_id, &allowance);
+
+        EventLog {
+            standard: KEYPOM_STANDARD_NAME.to_string(),
+            version: KEYPOM_STANDARD_VERSION.to_string(),
+            event: EventLogVariant::AllowanceSpend(AllowanceSpendLog {
+                funder_id: funder_id.to_string(),
+                spender_id: spender_id.to_string(),
+                drop_id: drop_id.to_string(),
+                amount: amount.to_string(),
+                total_spent: allowance.total_spent.to_string(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use near_sdk::test_utils::{get_created_receipts, VMContextBuilder};
+use near_sdk::{mock::MockAction, testing_env, PromiseResult};
+
+use crate::*;
+
+pub(crate) fn keypom_id() -> AccountId {
+    "keypom.near".parse().unwrap()
+}
+
+pub(crate) fn owner_id() -> AccountId {
+    "owner.near".parse().unwrap()
+}
+
+pub(crate) fn funder_id() -> AccountId {
+    "funder.near".parse().unwrap()
+}
+
+/// Context for a call to Keypom made by *predecessor_id* with the most gas that can be attached
+pub(crate) fn context(predecessor_id: &AccountId) -> VMContextBuilder {
+    let mut builder = VMContextBuilder::new();
+    builder
+        .current_account_id(keypom_id())
+        .signer_account_id(predecessor_id.clone())
+        .predecessor_account_id(predecessor_id.clone())
+        .account_balance(NearToken::from_near(1_000))
+        .prepaid_gas(MAX_GAS_ATTACHABLE);
+    builder
+}
+
+/// Deploy a fresh Keypom contract without any fees
+pub(crate) fn setup_contract() -> Keypom {
+    testing_env!(context(&owner_id()).build());
+    Keypom::new("near".parse().unwrap(), owner_id(), None)
+}
+
+/// ED25519 public key whose bytes are all *seed*
+pub(crate) fn public_key(seed: u8) -> PublicKey {
+    PublicKey::from_parts(CurveType::ED25519, vec![seed; 32]).unwrap()
+}
+
+/// Create a drop as the funder with a key for every public key. The keys are owned by *key_owner* if specified.
+/// *asset_data* and *drop_config* are passed in as JSON the same way they would be when calling the contract
+pub(crate) fn create_drop(
+    contract: &mut Keypom,
+    drop_id: &str,
+    public_keys: &[PublicKey],
+    key_owner: Option<AccountId>,
+    asset_data: serde_json::Value,
+    drop_config: serde_json::Value,
+) {
+    testing_env!(context(&funder_id())
+        .attached_deposit(NearToken::from_near(100))
+        .build());
+    let key_data = public_keys
+        .iter()
+        .map(|public_key| ExtKeyData {
+            public_key: public_key.clone(),
+            password_by_use: None,
+            metadata: None,
+            key_owner: key_owner.clone(),
+            token_metadata: None,
+            time_config: None,
+        })
+        .collect();
+    contract.create_drop(
+        Some(drop_id.to_string()),
+        key_data,
+        serde_json::from_value(asset_data).unwrap(),
+        serde_json::from_value(drop_config).unwrap(),
+        None,
+    );
+}
+
+/// Add $NEAR to an account's balance
+pub(crate) fn add_to_balance(contract: &mut Keypom, account_id: &AccountId, amount: NearToken) {
+    testing_env!(context(account_id).attached_deposit(amount).build());
+    contract.add_to_balance();
+}
+
+/// Call Keypom with a transaction signed by *public_key* the same way a drop key would
+pub(crate) fn sign_with_key(public_key: &PublicKey) {
+    testing_env!(context(&keypom_id())
+        .signer_account_pk(public_key.clone())
+        .build());
+}
+
+/// Call one of Keypom's callbacks with the result of the promise it was waiting on
+pub(crate) fn resolve_callback(result: PromiseResult) {
+    // The SDK's helper is the only way to set promise results without depending on the runtime's fee config
+    #[allow(deprecated)]
+    near_sdk::test_utils::testing_env_with_promise_results(context(&keypom_id()).build(), result);
+}
+
+/// Arguments that the last call scheduled *method_name* to be called with
+pub(crate) fn scheduled_call_args(method_name: &str) -> serde_json::Value {
+    get_created_receipts()
+        .into_iter()
+        .flat_map(|receipt| receipt.actions)
+        .find_map(|action| match action {
+            MockAction::FunctionCallWeight {
+                method_name: name,
+                args,
+                ..
+            } if name == method_name.as_bytes() => Some(serde_json::from_slice(&args).unwrap()),
+            _ => None,
+        })
+        .expect("Call was not scheduled")
+}
+
+/// Every $NEAR transfer the last call sent to *receiver_id*
+pub(crate) fn transfers_to(receiver_id: &AccountId) -> Vec<Balance> {
+    get_created_receipts()
+        .into_iter()
+        .filter(|receipt| &receipt.receiver_id == receiver_id)
+        .flat_map(|receipt| receipt.actions)
+        .filter_map(|action| match action {
+            MockAction::Transfer { deposit, .. } => Some(deposit.as_yoctonear()),
+            _ => None,
+        })
+        .collect()
+}
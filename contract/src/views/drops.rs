@@ -16,6 +16,20 @@ impl Keypom {
         drop.to_external_drop(drop_id)
     }
 
+    /// Allows you to query whether a drop holds enough FTs and NFTs for every remaining use of every key
+    ///
+    /// Requirements:
+    /// * Panics if the drop does not exist.
+    ///
+    /// Arguments:
+    /// * `drop_id` the ID for the drop
+    ///
+    /// Returns a vector of `ExtAssetFundingStatus` with the shortfall or surplus for every FT and NFT asset in the drop
+    pub fn get_drop_funding_status(&self, drop_id: DropId) -> Vec<ExtAssetFundingStatus> {
+        let drop = self.drop_by_id.get(&drop_id).expect("Drop not found");
+        drop.get_funding_status()
+    }
+
     /// Allows you to query for the information about a batch of drops all at once with 1 function.
     ///
     ///
@@ -89,4 +103,142 @@ impl Keypom {
             //since we turned the keys into an iterator, we need to turn it back into a vector to return
             .collect();
     }
+
+    /// Allows you to query for how many referral rewards an account has earned in a drop
+    ///
+    /// Requirements:
+    /// * Panics if the drop does not exist.
+    ///
+    /// Arguments:
+    /// * `drop_id` the ID for the drop.
+    /// * `referrer_id` the account that referred the claims.
+    ///
+    /// Returns the number of rewards as a `u32`. If the account hasn't referred anyone, returns `0`
+    pub fn get_referral_count(&self, drop_id: DropId, referrer_id: AccountId) -> u32 {
+        self.drop_by_id
+            .get(&drop_id)
+            .expect("no drop found")
+            .referrals_by_account
+            .get(&referrer_id)
+            .unwrap_or(0)
+    }
+
+    /// Allows you to query for how many FTs a contributor other than the funder has deposited into a drop and can still reclaim
+    ///
+    /// Requirements:
+    /// * Panics if the drop does not exist.
+    ///
+    /// Arguments:
+    /// * `drop_id` the ID for the drop.
+    /// * `sender_id` the account that sent the FTs.
+    /// * `ft_contract_id` the FT contract the tokens came from.
+    /// * `referral_asset` whether to look at the drop's referral rewards instead of the claim assets.
+    ///
+    /// Returns the number of tokens as a `U128`. If the account hasn't deposited anything, returns `0`
+    pub fn get_ft_deposit(
+        &self,
+        drop_id: DropId,
+        sender_id: AccountId,
+        ft_contract_id: AccountId,
+        referral_asset: Option<bool>,
+    ) -> U128 {
+        let asset_id = if referral_asset.unwrap_or(false) {
+            format!("{}{}", REFERRAL_ASSET_PREFIX, ft_contract_id)
+        } else {
+            ft_contract_id.to_string()
+        };
+        U128(
+            self.drop_by_id
+                .get(&drop_id)
+                .expect("no drop found")
+                .ft_deposits_by_sender
+                .get(&(sender_id, asset_id))
+                .unwrap_or(0),
+        )
+    }
+
+    /// Allows you to query for the progress of a crowdfunded drop
+    ///
+    /// Requirements:
+    /// * Panics if the drop does not exist.
+    ///
+    /// Arguments:
+    /// * `drop_id` the ID for the drop.
+    ///
+    /// Returns an `ExtCrowdfundStatus` or `None` if the drop isn't crowdfunded
+    pub fn get_crowdfund_status(&self, drop_id: DropId) -> Option<ExtCrowdfundStatus> {
+        let drop = self.drop_by_id.get(&drop_id).expect("no drop found");
+        let crowdfund = drop.config.as_ref()?.crowdfund.as_ref()?;
+
+        Some(ExtCrowdfundStatus {
+            status: drop.get_crowdfund_status()?,
+            ft_contract_id: crowdfund.ft_contract_id.clone(),
+            target: crowdfund.target,
+            raised: U128(drop.crowdfund_raised),
+            deadline: crowdfund.deadline,
+            collected: drop.crowdfund_collected,
+        })
+    }
+
+    /// Allows you to query for how much $NEAR an account has contributed to a crowdfunded drop and not yet reclaimed
+    ///
+    /// Requirements:
+    /// * Panics if the drop does not exist.
+    ///
+    /// Arguments:
+    /// * `drop_id` the ID for the drop.
+    /// * `contributor_id` the account that contributed.
+    ///
+    /// Returns the amount as a `U128`. If the account hasn't contributed, returns `0`
+    pub fn get_near_contribution(&self, drop_id: DropId, contributor_id: AccountId) -> U128 {
+        U128(
+            self.drop_by_id
+                .get(&drop_id)
+                .expect("no drop found")
+                .near_contributions_by_sender
+                .get(&contributor_id)
+                .unwrap_or(0),
+        )
+    }
+
+    /// Allows you to paginate through the referral leaderboard for a drop
+    ///
+    /// Requirements:
+    /// * Panics if the drop does not exist.
+    ///
+    /// Arguments:
+    /// * `drop_id` the ID for the drop.
+    /// * `from_index` where to start paginating from. If not specified, will start from 0 index.
+    /// * `limit` how many referrers to return. If not specified, will return 50 referrers.
+    ///
+    /// Returns a vector of `ExtReferralStats` objects sorted by the number of rewards earned (highest first)
+    pub fn get_referral_leaderboard(
+        &self,
+        drop_id: DropId,
+        from_index: Option<U128>,
+        limit: Option<u64>,
+    ) -> Vec<ExtReferralStats> {
+        let drop = self.drop_by_id.get(&drop_id).expect("no drop found");
+
+        let mut leaderboard: Vec<ExtReferralStats> = drop
+            .referrals_by_account
+            .iter()
+            .map(|(referrer_id, num_rewards)| ExtReferralStats {
+                referrer_id,
+                num_rewards,
+            })
+            .collect();
+        leaderboard.sort_by_key(|stats| std::cmp::Reverse(stats.num_rewards));
+
+        // Where to start pagination - if we have a from_index, we'll use that - otherwise start from 0 index
+        let start = u128::from(from_index.unwrap_or(U128(0)));
+
+        leaderboard
+            .into_iter()
+            // Skip to the index we specified in the start variable
+            .skip(start as usize)
+            // Take the first "limit" elements in the vector. If we didn't specify a limit, use 50
+            .take(limit.unwrap_or(50) as usize)
+            .collect()
+    }
 }
@@ -58,4 +58,82 @@ impl Keypom {
             vec![]
         }
     }
+
+    /// Query for the spending allowance that a funder has granted a given account
+    ///
+    /// Arguments:
+    /// * `funder_id` The account ID of the funder whose balance is being spent
+    /// * `spender_id` The account ID that was granted the allowance
+    ///
+    /// Returns an `ExtSpendingAllowance` if the funder granted the account an allowance, otherwise `None`
+    pub fn get_spending_allowance(
+        &self,
+        funder_id: AccountId,
+        spender_id: AccountId,
+    ) -> Option<ExtSpendingAllowance> {
+        self.spending_allowances_by_funder
+            .get(&funder_id)
+            .and_then(|allowances| allowances.get(&spender_id))
+            .map(|allowance| allowance.to_external(spender_id))
+    }
+
+    /// Allows you to paginate through all the spending allowances a funder has granted
+    ///
+    /// Arguments:
+    /// * `funder_id` The account ID of the funder to query for
+    /// * `from_index` where to start paginating from. If not specified, will start from 0 index.
+    /// * `limit` how many allowances to return. If not specified, will return 50 allowances.
+    ///
+    /// Returns a vector of `ExtSpendingAllowance` objects
+    pub fn get_spending_allowances_for_funder(
+        &self,
+        funder_id: AccountId,
+        from_index: Option<U128>,
+        limit: Option<u64>,
+    ) -> Vec<ExtSpendingAllowance> {
+        if let Some(allowances) = self.spending_allowances_by_funder.get(&funder_id) {
+            let start = u128::from(from_index.unwrap_or(U128(0)));
+
+            allowances
+                .iter()
+                .skip(start as usize)
+                .take(limit.unwrap_or(50) as usize)
+                .map(|(spender_id, allowance)| allowance.to_external(spender_id))
+                .collect()
+        } else {
+            vec![]
+        }
+    }
+
+    /// Allows you to paginate through all the drop templates a funder has saved
+    ///
+    /// Arguments:
+    /// * `funder_id` The account ID of the funder to query for
+    /// * `from_index` where to start paginating from. If not specified, will start from 0 index.
+    /// * `limit` how many templates to return. If not specified, will return 50 templates.
+    ///
+    /// Returns a vector of `ExtDropTemplate` objects
+    pub fn get_drop_templates_for_funder(
+        &self,
+        funder_id: AccountId,
+        from_index: Option<U128>,
+        limit: Option<u64>,
+    ) -> Vec<ExtDropTemplate> {
+        if let Some(templates) = self.drop_templates_by_funder.get(&funder_id) {
+            let start = u128::from(from_index.unwrap_or(U128(0)));
+
+            templates
+                .iter()
+                .skip(start as usize)
+                .take(limit.unwrap_or(50) as usize)
+                .map(|(template_name, template)| ExtDropTemplate {
+                    template_name,
+                    asset_data: template.asset_data,
+                    drop_config: template.config,
+                })
+                .collect()
+        } else {
+            vec![]
+        }
+    }
 }
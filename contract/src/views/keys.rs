@@ -107,12 +107,25 @@ impl Keypom {
             }
         }
 
+        // The key's own time config takes precedence over the use's
+        let next_claimable_timestamp = key_info
+            .time_config
+            .as_ref()
+            .or_else(|| use_config.as_ref().and_then(|c| c.time.as_ref()))
+            .and_then(|time| {
+                time.schedule.as_ref().map(|schedule| {
+                    get_next_claimable_timestamp(schedule, time.start.unwrap_or(0), &key_info)
+                })
+            });
+
         Ok(ExtKeyInfo {
             yoctonear: U128(yoctonear),
             ft_list,
             nft_list,
             fc_list,
             metadata: key_info.metadata,
+            time_config: key_info.time_config,
+            next_claimable_timestamp,
             required_gas: required_gas.as_gas().to_string(),
             uses_remaining: key_info.remaining_uses,
             drop_id,
@@ -0,0 +1,154 @@
+use crate::*;
+
+#[near_bindgen]
+impl Keypom {
+    /// Quote how much it would cost to create a drop. This uses the same cost calculations as `create_drop`
+    /// With the storage being estimated rather than measured.
+    ///
+    /// Arguments:
+    /// * `asset_data` The asset data that would be passed into `create_drop`
+    /// * `drop_config` The drop config that would be passed into `create_drop`
+    /// * `num_keys` How many keys would be added when the drop is created
+    /// * `key_data_sizes` The number of bytes of metadata, passwords and owner for each key (if any)
+    /// * `funder_id` The account whose fee structure should be used. If not specified, the default fees are used.
+    ///
+    /// Returns an `ExtCostQuote` with a breakdown of the costs
+    pub fn quote_create_drop(
+        &self,
+        asset_data: Vec<ExtAssetDataForUses>,
+        drop_config: Option<DropConfig>,
+        num_keys: u64,
+        key_data_sizes: Option<Vec<u64>>,
+        funder_id: Option<AccountId>,
+    ) -> ExtCostQuote {
+        // Assets are only stored in memory so nothing is written to the contract's storage
+        let mut asset_by_id: HashMap<AssetId, InternalAsset> = HashMap::new();
+        let (asset_data_for_uses, max_key_uses) =
+            store_asset_data_for_uses(&asset_data, &mut asset_by_id);
+        let (total_cost_per_key, total_allowance_per_key) = get_costs_per_new_key(
+            &drop_config,
+            max_key_uses,
+            &asset_by_id,
+            &asset_data_for_uses,
+        );
+
+        // Estimate the storage for the drop's asset data and config on top of the keys
+        let asset_bytes: usize = asset_by_id
+            .iter()
+            .map(|(asset_id, asset)| asset_id.len() + borsh_len(asset))
+            .sum();
+        let estimated_storage_bytes = ESTIMATED_BYTES_PER_DROP
+            + (asset_bytes + borsh_len(&asset_data_for_uses) + borsh_len(&drop_config)) as u64
+            + estimate_key_bytes(num_keys, key_data_sizes);
+
+        let fees_for_user = funder_id.as_ref().map(|f| self.get_fees_for_user(f));
+        let pay_it_forward_reserve = self.get_pay_it_forward_reserve(
+            None,
+            funder_id.as_ref(),
+            &asset_data_for_uses,
+            total_cost_per_key
+                + total_allowance_per_key
+                + fees_for_user
+                    .as_ref()
+                    .unwrap_or(&self.fee_structure)
+                    .per_key,
+        );
+
+        let mut quote = self.internal_quote_costs(
+            fees_for_user,
+            num_keys,
+            true,
+            total_cost_per_key,
+            total_allowance_per_key,
+            estimated_storage_bytes,
+        );
+        // Pay it forward keys are reserved up front when the drop is created
+        quote.costs.pay_it_forward_cost = U128(pay_it_forward_reserve);
+        quote.costs.total_cost = U128(quote.costs.total_cost.0 + pay_it_forward_reserve);
+        quote
+    }
+
+    /// Quote how much it would cost to add keys to a drop. This uses the same cost calculations as `add_keys`
+    /// With the storage being estimated rather than measured.
+    ///
+    /// Requirements:
+    /// * Panics if the drop does not exist.
+    ///
+    /// Arguments:
+    /// * `drop_id` The drop that keys would be added to
+    /// * `num_keys` How many keys would be added
+    /// * `key_data_sizes` The number of bytes of metadata, passwords and owner for each key (if any)
+    /// * `account_id` The account adding the keys whose fee structure should be used. If not specified, the drop funder's fees are used.
+    ///
+    /// Returns an `ExtCostQuote` with a breakdown of the costs
+    pub fn quote_add_keys(
+        &self,
+        drop_id: DropId,
+        num_keys: u64,
+        key_data_sizes: Option<Vec<u64>>,
+        account_id: Option<AccountId>,
+    ) -> ExtCostQuote {
+        let drop = self.drop_by_id.get(&drop_id).expect("No drop found");
+        let (total_cost_per_key, total_allowance_per_key) = get_costs_per_new_key(
+            &drop.config,
+            drop.max_key_uses,
+            &drop.asset_by_id,
+            &drop.asset_data_for_uses,
+        );
+
+        let account_id = account_id.unwrap_or(drop.funder_id);
+        self.internal_quote_costs(
+            Some(self.get_fees_for_user(&account_id)),
+            num_keys,
+            false,
+            total_cost_per_key,
+            total_allowance_per_key,
+            estimate_key_bytes(num_keys, key_data_sizes),
+        )
+    }
+
+    /// Tally up the costs for a quote the same way they would be charged
+    fn internal_quote_costs(
+        &self,
+        fees_for_user: Option<KeypomFees>,
+        num_keys: u64,
+        did_create_drop: bool,
+        asset_cost_per_key: Balance,
+        allowance_per_key: Balance,
+        estimated_storage_bytes: u64,
+    ) -> ExtCostQuote {
+        let fee_structure = fees_for_user.unwrap_or(self.fee_structure.clone());
+        let costs = self.get_drop_costs(
+            &fee_structure,
+            num_keys as usize,
+            did_create_drop,
+            asset_cost_per_key,
+            allowance_per_key,
+            estimated_storage_bytes,
+        );
+
+        ExtCostQuote {
+            costs: costs.to_external(),
+            fee_structure,
+            estimated_storage_bytes,
+        }
+    }
+}
+
+/// Estimate the storage used by a set of keys given the size of each key's data
+fn estimate_key_bytes(num_keys: u64, key_data_sizes: Option<Vec<u64>>) -> u64 {
+    let key_data_sizes = key_data_sizes.unwrap_or_default();
+    require!(
+        key_data_sizes.len() as u64 <= num_keys,
+        "Cannot pass in more key data sizes than keys"
+    );
+
+    num_keys * ESTIMATED_BYTES_PER_KEY + key_data_sizes.iter().sum::<u64>()
+}
+
+/// Number of bytes a value takes up once serialized into storage
+fn borsh_len<T: BorshSerialize>(value: &T) -> usize {
+    near_sdk::borsh::to_vec(value)
+        .expect("Failed to serialize")
+        .len()
+}
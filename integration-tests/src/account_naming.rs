@@ -0,0 +1,82 @@
+use crate::*;
+
+/// New accounts created through `create_account_and_claim` must follow the use's naming rules
+/// And can't contain any of the funder's banned words
+pub async fn test_account_naming_rules(
+    worker: &Worker<Sandbox>,
+    owner: &Account,
+) -> anyhow::Result<()> {
+    let keypom_contract = deploy_keypom(worker, owner).await?;
+    let linkdrop = deploy_linkdrop(worker).await?;
+    let funder = create_account(owner, "naming-funder", NearToken::from_near(20)).await?;
+
+    let keys = generate_keypairs(3);
+    let res = funder
+        .call(keypom_contract.id(), "create_drop")
+        .args_json(json!({
+            "drop_id": "naming_drop",
+            "key_data": keys.iter().map(|k| json!({ "public_key": k.public_key() })).collect::<Vec<_>>(),
+            "asset_data": [{
+                "uses": 1,
+                "assets": [{ "yoctonear": NearToken::from_near(1).as_yoctonear().to_string() }],
+                "config": {
+                    "root_account_id": linkdrop.id(),
+                    "account_name_rules": { "prefix": "kp", "charset": "alphanumeric" }
+                }
+            }]
+        }))
+        .deposit(NearToken::from_near(5))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+
+    // A name that follows the rules creates the account and claims the assets
+    let new_account_id: AccountId = format!("kpalice.{}", linkdrop.id()).parse()?;
+    let new_key = generate_keypairs(1).remove(0);
+    let res = claim_with_key(
+        &keypom_contract,
+        &keys[0],
+        "create_account_and_claim",
+        json!({ "new_account_id": new_account_id, "new_public_key": new_key.public_key() }),
+        NearGas::from_tgas(0),
+    )
+    .await?;
+    assert!(res.is_success());
+    assert!(worker.view_account(&new_account_id).await?.balance >= NearToken::from_near(1));
+
+    // A name that breaks the rules is rejected before any account is created
+    let bad_account_id: AccountId = format!("alice.{}", linkdrop.id()).parse()?;
+    let res = claim_with_key(
+        &keypom_contract,
+        &keys[1],
+        "create_account_and_claim",
+        json!({ "new_account_id": bad_account_id, "new_public_key": new_key.public_key() }),
+        NearGas::from_tgas(0),
+    )
+    .await?;
+    assert_failed_with(res, "must start with kp");
+    assert!(worker.view_account(&bad_account_id).await.is_err());
+
+    // Banned words set by the funder are rejected as well
+    let res = funder
+        .call(keypom_contract.id(), "set_banned_account_words")
+        .args_json(json!({ "drop_id": "naming_drop", "banned_words": ["scam"] }))
+        .deposit(NearToken::from_millinear(10))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+
+    let banned_account_id: AccountId = format!("kpscam.{}", linkdrop.id()).parse()?;
+    let res = claim_with_key(
+        &keypom_contract,
+        &keys[2],
+        "create_account_and_claim",
+        json!({ "new_account_id": banned_account_id, "new_public_key": new_key.public_key() }),
+        NearGas::from_tgas(0),
+    )
+    .await?;
+    assert_failed_with(res, "contains a banned word");
+
+    println!("      Passed ✅ test_account_naming_rules");
+    Ok(())
+}
@@ -0,0 +1,119 @@
+use crate::*;
+
+/// Approvals on NFT keys should be limited to the drop's approved marketplaces, stop working once they
+/// Expire and all be cleared by `nft_revoke_all`. The caller pays for the storage their approvals take up.
+pub async fn test_approvals(worker: &Worker<Sandbox>, owner: &Account) -> anyhow::Result<()> {
+    let keypom_contract = deploy_keypom(worker, owner).await?;
+    let funder = create_account(owner, "approval-funder", NearToken::from_near(20)).await?;
+    let holder = create_account(owner, "approval-holder", NearToken::from_near(5)).await?;
+    let market = create_account(owner, "approval-market", NearToken::from_near(5)).await?;
+    let outsider = create_account(owner, "approval-outsider", NearToken::from_near(5)).await?;
+
+    let keys = generate_keypairs(1);
+    let res = funder
+        .call(keypom_contract.id(), "create_drop")
+        .args_json(json!({
+            "drop_id": "ticket_drop",
+            "key_data": [{ "public_key": keys[0].public_key(), "key_owner": holder.id() }],
+            "asset_data": [{ "uses": 1, "assets": [null] }],
+            "drop_config": { "nft_keys_config": { "approved_marketplaces": [market.id()] } }
+        }))
+        .deposit(NearToken::from_near(1))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+
+    // Only approved marketplaces can be given access to the key
+    let res = holder
+        .call(keypom_contract.id(), "nft_approve")
+        .args_json(json!({ "token_id": "ticket_drop:0", "account_id": outsider.id() }))
+        .deposit(NearToken::from_millinear(10))
+        .transact()
+        .await?;
+    assert_failed_with(res, "Account is not an approved marketplace for this drop");
+
+    // The approval's storage has to be paid for by the holder
+    let res = holder
+        .call(keypom_contract.id(), "nft_approve")
+        .args_json(json!({ "token_id": "ticket_drop:0", "account_id": market.id() }))
+        .transact()
+        .await?;
+    assert!(res.is_failure());
+
+    // An approval that expires shortly stops working once the time has passed
+    let now = worker.view_block().await?.timestamp();
+    let res = holder
+        .call(keypom_contract.id(), "nft_approve")
+        .args_json(json!({
+            "token_id": "ticket_drop:0",
+            "account_id": market.id(),
+            "expires_at": now + 1_000_000_000
+        }))
+        .deposit(NearToken::from_millinear(10))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+    worker.fast_forward(100).await?;
+
+    let is_approved = keypom_contract
+        .view("nft_is_approved")
+        .args_json(json!({ "token_id": "ticket_drop:0", "approved_account_id": market.id() }))
+        .await?
+        .json::<bool>()?;
+    assert!(!is_approved);
+    let res = market
+        .call(keypom_contract.id(), "nft_transfer")
+        .args_json(json!({
+            "token_id": "ticket_drop:0",
+            "receiver_id": market.id(),
+            "memo": generate_keypairs(1)[0].public_key()
+        }))
+        .deposit(NearToken::from_yoctonear(1))
+        .transact()
+        .await?;
+    assert_failed_with(res, "Approval has expired");
+
+    // A fresh approval without an expiry works until everything is revoked
+    let res = holder
+        .call(keypom_contract.id(), "nft_approve")
+        .args_json(json!({ "token_id": "ticket_drop:0", "account_id": market.id() }))
+        .deposit(NearToken::from_millinear(10))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+    let is_approved = keypom_contract
+        .view("nft_is_approved")
+        .args_json(json!({ "token_id": "ticket_drop:0", "approved_account_id": market.id() }))
+        .await?
+        .json::<bool>()?;
+    assert!(is_approved);
+
+    let res = holder
+        .call(keypom_contract.id(), "nft_revoke_all")
+        .args_json(json!({ "token_id": "ticket_drop:0" }))
+        .deposit(NearToken::from_yoctonear(1))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+    let res = market
+        .call(keypom_contract.id(), "nft_transfer")
+        .args_json(json!({
+            "token_id": "ticket_drop:0",
+            "receiver_id": market.id(),
+            "memo": generate_keypairs(1)[0].public_key()
+        }))
+        .deposit(NearToken::from_yoctonear(1))
+        .transact()
+        .await?;
+    assert_failed_with(res, "Unauthorized");
+
+    let token = keypom_contract
+        .view("nft_token")
+        .args_json(json!({ "token_id": "ticket_drop:0" }))
+        .await?
+        .json::<serde_json::Value>()?;
+    assert_eq!(token["owner_id"], json!(holder.id()));
+
+    println!("      Passed ✅ test_approvals");
+    Ok(())
+}
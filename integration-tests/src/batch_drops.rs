@@ -0,0 +1,106 @@
+use crate::*;
+
+/// Batches should create every drop with its own keys and return each drop's costs. An invalid drop anywhere
+/// In the batch should stop the whole batch before anything is created.
+pub async fn test_batch_drops(worker: &Worker<Sandbox>, owner: &Account) -> anyhow::Result<()> {
+    let keypom_contract = deploy_keypom(worker, owner).await?;
+    let funder = create_account(owner, "batch-funder", NearToken::from_near(30)).await?;
+
+    let res = funder
+        .call(keypom_contract.id(), "set_funder_metadata")
+        .args_json(
+            json!({ "metadata": json!({ "name": "batch funder", "team": "a" }).to_string() }),
+        )
+        .deposit(NearToken::from_millinear(10))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+
+    let near_asset = |amount: NearToken| json!([{ "uses": 1, "assets": [{ "yoctonear": amount.as_yoctonear().to_string() }] }]);
+    let keys = generate_keypairs(3);
+
+    // The last drop has too many keys so nothing in the batch is created
+    let too_many_keys = generate_keypairs(101)
+        .iter()
+        .map(|k| json!({ "public_key": k.public_key() }))
+        .collect::<Vec<_>>();
+    let res = funder
+        .call(keypom_contract.id(), "create_drop_batch")
+        .args_json(json!({
+            "drop_ids": ["batch_drop_1", "batch_drop_2"],
+            "key_datas": [[{ "public_key": keys[0].public_key() }], too_many_keys],
+            "asset_datas": [near_asset(NearToken::from_near(1)), near_asset(NearToken::from_near(1))],
+            "drop_configs": [null, null]
+        }))
+        .deposit(NearToken::from_near(10))
+        .transact()
+        .await?;
+    assert_failed_with(res, "Cannot add more than 100 keys at a time");
+    let res = keypom_contract
+        .view("get_drop_information")
+        .args_json(json!({ "drop_id": "batch_drop_1" }))
+        .await;
+    assert!(res.is_err());
+
+    // Each drop gets its own keys and the funder's metadata is merged rather than replaced
+    let res = funder
+        .call(keypom_contract.id(), "create_drop_batch")
+        .args_json(json!({
+            "drop_ids": ["batch_drop_1", "batch_drop_2"],
+            "key_datas": [
+                [{ "public_key": keys[0].public_key() }],
+                [{ "public_key": keys[1].public_key() }, { "public_key": keys[2].public_key() }]
+            ],
+            "asset_datas": [near_asset(NearToken::from_near(1)), near_asset(NearToken::from_near(2))],
+            "drop_configs": [null, null],
+            "change_user_metadata": json!({ "team": "b" }).to_string()
+        }))
+        .deposit(NearToken::from_near(10))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+    let results = res.json::<serde_json::Value>()?;
+    assert_eq!(results[0]["drop_id"], "batch_drop_1");
+    assert_eq!(results[1]["drop_id"], "batch_drop_2");
+
+    let cost_of = |costs: &serde_json::Value, field: &str| -> u128 {
+        costs[field].as_str().unwrap().parse().unwrap()
+    };
+    for (result, asset_cost) in results.as_array().unwrap().iter().zip([
+        NearToken::from_near(1).as_yoctonear(),
+        NearToken::from_near(4).as_yoctonear(),
+    ]) {
+        let costs = &result["costs"];
+        assert_eq!(cost_of(costs, "asset_cost"), asset_cost);
+        assert!(cost_of(costs, "storage_cost") > 0);
+        assert_eq!(
+            cost_of(costs, "total_cost"),
+            cost_of(costs, "storage_cost")
+                + cost_of(costs, "asset_cost")
+                + cost_of(costs, "allowance_cost")
+                + cost_of(costs, "fees")
+                + cost_of(costs, "pay_it_forward_cost")
+        );
+    }
+
+    for (drop_id, key_supply) in [("batch_drop_1", 1), ("batch_drop_2", 2)] {
+        let supply = keypom_contract
+            .view("get_key_supply_for_drop")
+            .args_json(json!({ "drop_id": drop_id }))
+            .await?
+            .json::<u64>()?;
+        assert_eq!(supply, key_supply);
+    }
+
+    let funder_info = keypom_contract
+        .view("get_funder_info")
+        .args_json(json!({ "account_id": funder.id() }))
+        .await?
+        .json::<serde_json::Value>()?;
+    let metadata: serde_json::Value =
+        serde_json::from_str(funder_info["metadata"].as_str().unwrap())?;
+    assert_eq!(metadata, json!({ "name": "batch funder", "team": "b" }));
+
+    println!("      Passed ✅ test_batch_drops");
+    Ok(())
+}
@@ -0,0 +1,101 @@
+use crate::*;
+
+/// Gated uses should only send their assets to accounts that pass the gate. Failing the gate gives the use
+/// And its claim time back to the key and gated uses can't be claimed to accounts that don't exist yet
+pub async fn test_claim_gates(worker: &Worker<Sandbox>, owner: &Account) -> anyhow::Result<()> {
+    let keypom_contract = deploy_keypom(worker, owner).await?;
+    // A second Keypom instance acts as the NFT contract the gate checks since it implements `nft_supply_for_owner`
+    let nft_contract = deploy_keypom(worker, owner).await?;
+    let funder = create_account(owner, "gate-funder", NearToken::from_near(20)).await?;
+    let holder = create_account(owner, "gate-holder", NearToken::from_near(5)).await?;
+    let outsider = create_account(owner, "gate-outsider", NearToken::from_near(5)).await?;
+
+    // Give the holder an NFT key on the gate's contract
+    let nft_key = generate_keypairs(1).remove(0);
+    let res = funder
+        .call(nft_contract.id(), "create_drop")
+        .args_json(json!({
+            "drop_id": "membership_drop",
+            "key_data": [{ "public_key": nft_key.public_key(), "key_owner": holder.id() }],
+            "asset_data": [{ "uses": 1, "assets": [null] }]
+        }))
+        .deposit(NearToken::from_near(1))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+
+    let keys = generate_keypairs(2);
+    let res = funder
+        .call(keypom_contract.id(), "create_drop")
+        .args_json(json!({
+            "drop_id": "gated_drop",
+            "key_data": keys.iter().map(|k| json!({ "public_key": k.public_key() })).collect::<Vec<_>>(),
+            "asset_data": [{
+                "uses": 1,
+                "assets": [{ "yoctonear": NearToken::from_near(1).as_yoctonear().to_string() }],
+                "config": {
+                    "claim_gate": { "nft_holder": { "contract_id": nft_contract.id() } },
+                    // An hour long throttle that would block the next claim if the failed attempt counted
+                    "time": { "throttle": 3_600_000_000_000u64 }
+                }
+            }]
+        }))
+        .deposit(NearToken::from_near(5))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+
+    // An account without an NFT fails the gate and the use is given back to the key
+    let outsider_balance = outsider.view_account().await?.balance;
+    let res = claim_with_key(
+        &keypom_contract,
+        &keys[0],
+        "claim",
+        json!({ "account_id": outsider.id() }),
+        NearGas::from_tgas(0),
+    )
+    .await?;
+    assert!(res.is_success());
+    assert_eq!(res.json::<bool>()?, false);
+    assert_eq!(outsider.view_account().await?.balance, outsider_balance);
+    let key_info = keypom_contract
+        .view("get_key_information")
+        .args_json(json!({ "key": keys[0].public_key() }))
+        .await?
+        .json::<serde_json::Value>()?;
+    assert_eq!(key_info["uses_remaining"], 1);
+
+    // The holder passes the gate right away since the failed attempt didn't trip the throttle
+    let holder_balance = holder.view_account().await?.balance;
+    let res = claim_with_key(
+        &keypom_contract,
+        &keys[0],
+        "claim",
+        json!({ "account_id": holder.id() }),
+        NearGas::from_tgas(0),
+    )
+    .await?;
+    assert!(res.is_success());
+    assert_eq!(res.json::<bool>()?, true);
+    assert!(
+        holder.view_account().await?.balance
+            >= holder_balance.saturating_add(NearToken::from_near(1))
+    );
+
+    // New accounts can never pass the gate so they're rejected before being created
+    let new_account_id: AccountId = format!("gated.{}", owner.id()).parse()?;
+    let new_key = generate_keypairs(1).remove(0);
+    let res = claim_with_key(
+        &keypom_contract,
+        &keys[1],
+        "create_account_and_claim",
+        json!({ "new_account_id": new_account_id, "new_public_key": new_key.public_key() }),
+        NearGas::from_tgas(0),
+    )
+    .await?;
+    assert_failed_with(res, "Gated uses must be claimed to an existing account");
+    assert!(worker.view_account(&new_account_id).await.is_err());
+
+    println!("      Passed ✅ test_claim_gates");
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use crate::*;
+
+/// Quotes should break down the costs the same way they're charged when the drop is created or keys are added.
+/// Only the storage is estimated.
+pub async fn test_cost_quotes(worker: &Worker<Sandbox>, owner: &Account) -> anyhow::Result<()> {
+    let keypom_contract = deploy_keypom(worker, owner).await?;
+    let funder = create_account(owner, "quote-funder", NearToken::from_near(20)).await?;
+
+    let asset_data = json!([{
+        "uses": 2,
+        "assets": [{ "yoctonear": NearToken::from_near(1).as_yoctonear().to_string() }]
+    }]);
+    let quote = keypom_contract
+        .view("quote_create_drop")
+        .args_json(json!({
+            "asset_data": asset_data,
+            "num_keys": 2,
+            "key_data_sizes": [64],
+            "funder_id": funder.id()
+        }))
+        .await?
+        .json::<serde_json::Value>()?;
+    assert!(quote["estimated_storage_bytes"].as_u64().unwrap() > 0);
+
+    let keys = generate_keypairs(2);
+    let res = funder
+        .call(keypom_contract.id(), "create_drop_batch")
+        .args_json(json!({
+            "drop_ids": ["quoted_drop"],
+            "key_datas": [keys.iter().map(|k| json!({ "public_key": k.public_key() })).collect::<Vec<_>>()],
+            "asset_datas": [asset_data],
+            "drop_configs": [null]
+        }))
+        .deposit(NearToken::from_near(5))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+    let charged = &res.json::<serde_json::Value>()?[0]["costs"];
+
+    // Everything but the storage is charged exactly as quoted
+    for field in [
+        "asset_cost",
+        "allowance_cost",
+        "fees",
+        "pay_it_forward_cost",
+    ] {
+        assert_eq!(quote["costs"][field], charged[field], "{} differs", field);
+    }
+    assert_eq!(
+        quote["costs"]["asset_cost"],
+        NearToken::from_near(4).as_yoctonear().to_string()
+    );
+
+    // Quotes for adding keys don't include the per drop fee
+    let quote = keypom_contract
+        .view("quote_add_keys")
+        .args_json(json!({ "drop_id": "quoted_drop", "num_keys": 1 }))
+        .await?
+        .json::<serde_json::Value>()?;
+    assert_eq!(
+        quote["costs"]["asset_cost"],
+        NearToken::from_near(2).as_yoctonear().to_string()
+    );
+    assert_eq!(
+        quote["costs"]["fees"].as_str().unwrap(),
+        quote["fee_structure"]["per_key"].to_string()
+    );
+
+    // Quotes fail for drops that don't exist or when there's more key data than keys
+    let res = keypom_contract
+        .view("quote_add_keys")
+        .args_json(json!({ "drop_id": "missing_drop", "num_keys": 1 }))
+        .await;
+    assert!(format!("{:?}", res.unwrap_err()).contains("No drop found"));
+    let res = keypom_contract
+        .view("quote_create_drop")
+        .args_json(json!({ "asset_data": asset_data, "num_keys": 1, "key_data_sizes": [10, 10] }))
+        .await;
+    assert!(
+        format!("{:?}", res.unwrap_err()).contains("Cannot pass in more key data sizes than keys")
+    );
+
+    println!("      Passed ✅ test_cost_quotes");
+    Ok(())
+}
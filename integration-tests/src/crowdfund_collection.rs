@@ -0,0 +1,106 @@
+use crate::*;
+
+/// Once a crowdfund meets its target, the funder should be able to collect what was raised exactly once and
+/// Contributors should no longer be able to reclaim their $NEAR
+pub async fn test_crowdfund_collection(
+    worker: &Worker<Sandbox>,
+    owner: &Account,
+) -> anyhow::Result<()> {
+    let keypom_contract = deploy_keypom(worker, owner).await?;
+    let funder = create_account(owner, "crowdfund-funder", NearToken::from_near(20)).await?;
+    let contributor =
+        create_account(owner, "crowdfund-contributor", NearToken::from_near(10)).await?;
+
+    let deadline = worker.view_block().await?.timestamp() + 3_600_000_000_000;
+    let res = funder
+        .call(keypom_contract.id(), "create_drop")
+        .args_json(json!({
+            "drop_id": "crowd_drop",
+            "key_data": [{ "public_key": generate_keypairs(1)[0].public_key() }],
+            "asset_data": [{ "uses": 1, "assets": [null] }],
+            "drop_config": {
+                "crowdfund": {
+                    "target": NearToken::from_near(2).as_yoctonear().to_string(),
+                    "deadline": deadline
+                }
+            }
+        }))
+        .deposit(NearToken::from_near(1))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+
+    let collect = |account: Account| {
+        let keypom_id = keypom_contract.id().clone();
+        async move {
+            account
+                .call(&keypom_id, "collect_crowdfund")
+                .args_json(json!({ "drop_id": "crowd_drop" }))
+                .transact()
+                .await
+        }
+    };
+    let get_status = || {
+        let keypom_contract = keypom_contract.clone();
+        async move {
+            keypom_contract
+                .view("get_crowdfund_status")
+                .args_json(json!({ "drop_id": "crowd_drop" }))
+                .await?
+                .json::<serde_json::Value>()
+        }
+    };
+
+    assert_failed_with(
+        collect(funder.clone()).await?,
+        "Crowdfund has not met its target",
+    );
+
+    let res = contributor
+        .call(keypom_contract.id(), "contribute_to_drop")
+        .args_json(json!({ "drop_id": "crowd_drop" }))
+        .deposit(NearToken::from_near(3))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+    let raised = res.json::<U128>()?;
+    let status = get_status().await?;
+    assert_eq!(status["status"], "funded");
+    assert_eq!(status["collected"], false);
+
+    // Only the funder can collect and everything raised goes to their balance
+    assert_failed_with(
+        collect(contributor.clone()).await?,
+        "Only drop funder can collect the crowdfund",
+    );
+    let balance_before = keypom_contract
+        .view("get_user_balance")
+        .args_json(json!({ "account_id": funder.id() }))
+        .await?
+        .json::<U128>()?;
+    let res = collect(funder.clone()).await?;
+    assert!(res.is_success());
+    assert_eq!(res.json::<U128>()?, raised);
+    let balance_after = keypom_contract
+        .view("get_user_balance")
+        .args_json(json!({ "account_id": funder.id() }))
+        .await?
+        .json::<U128>()?;
+    assert_eq!(balance_after.0, balance_before.0 + raised.0);
+    assert_eq!(get_status().await?["collected"], true);
+
+    // The crowdfund can't be collected twice and contributions can't be reclaimed
+    assert_failed_with(
+        collect(funder.clone()).await?,
+        "Crowdfund has already been collected",
+    );
+    let res = contributor
+        .call(keypom_contract.id(), "reclaim_near_contribution")
+        .args_json(json!({ "drop_id": "crowd_drop" }))
+        .transact()
+        .await?;
+    assert_failed_with(res, "can only be reclaimed once the crowdfund has failed");
+
+    println!("      Passed ✅ test_crowdfund_collection");
+    Ok(())
+}
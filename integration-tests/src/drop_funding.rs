@@ -0,0 +1,110 @@
+use crate::*;
+
+/// The funding status of a drop should track the NFTs needed by every remaining use of every key as keys
+/// Are added and compare it to the NFTs the drop holds
+pub async fn test_drop_funding(worker: &Worker<Sandbox>, owner: &Account) -> anyhow::Result<()> {
+    // Keypom keys are NFTs so a second Keypom instance is used as the NFT contract
+    let keypom_contract = deploy_keypom(worker, owner).await?;
+    let nft_contract = deploy_keypom(worker, owner).await?;
+    let funder = create_account(owner, "funding-funder", NearToken::from_near(20)).await?;
+
+    // The funder holds two NFT keys that they'll send into their drop
+    let res = funder
+        .call(nft_contract.id(), "create_drop")
+        .args_json(json!({
+            "drop_id": "nft_source",
+            "key_data": generate_keypairs(2).iter().map(|k| json!({ "public_key": k.public_key(), "key_owner": funder.id() })).collect::<Vec<_>>(),
+            "asset_data": [{ "uses": 1, "assets": [null] }]
+        }))
+        .deposit(NearToken::from_near(2))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+
+    let res = funder
+        .call(keypom_contract.id(), "create_drop")
+        .args_json(json!({
+            "drop_id": "nft_drop",
+            "key_data": [{ "public_key": generate_keypairs(1)[0].public_key() }],
+            "asset_data": [{ "uses": 2, "assets": [{ "nft_contract_id": nft_contract.id() }] }]
+        }))
+        .deposit(NearToken::from_near(2))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+    let res = funder
+        .call(keypom_contract.id(), "add_to_balance")
+        .deposit(NearToken::from_near(1))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+
+    let get_status = || {
+        let keypom_contract = keypom_contract.clone();
+        async move {
+            let drop = keypom_contract
+                .view("get_drop_information")
+                .args_json(json!({ "drop_id": "nft_drop" }))
+                .await?
+                .json::<serde_json::Value>()?;
+            let status = keypom_contract
+                .view("get_drop_funding_status")
+                .args_json(json!({ "drop_id": "nft_drop" }))
+                .await?
+                .json::<serde_json::Value>()?;
+            anyhow::Ok((drop["fully_funded"].as_bool().unwrap(), status[0].clone()))
+        }
+    };
+
+    // Both uses of the key need an NFT
+    let (fully_funded, status) = get_status().await?;
+    assert!(!fully_funded);
+    assert_eq!(status["required"], "2");
+    assert_eq!(status["held"], "0");
+    assert_eq!(status["shortfall"], "2");
+
+    for token_id in ["nft_source:0", "nft_source:1"] {
+        let res = funder
+            .call(nft_contract.id(), "nft_transfer_call")
+            .args_json(json!({
+                "receiver_id": keypom_contract.id(),
+                "token_id": token_id,
+                "memo": generate_keypairs(1)[0].public_key(),
+                "msg": "nft_drop"
+            }))
+            .deposit(NearToken::from_yoctonear(1))
+            .gas(NearGas::from_tgas(100))
+            .transact()
+            .await?;
+        assert!(res.is_success());
+    }
+    let (fully_funded, status) = get_status().await?;
+    assert!(fully_funded);
+    assert_eq!(status["held"], "2");
+    assert_eq!(status["shortfall"], "0");
+
+    // A new key needs more NFTs than the drop holds
+    let res = funder
+        .call(keypom_contract.id(), "add_keys")
+        .args_json(json!({
+            "drop_id": "nft_drop",
+            "key_data": [{ "public_key": generate_keypairs(1)[0].public_key() }]
+        }))
+        .deposit(NearToken::from_near(1))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+    let (fully_funded, status) = get_status().await?;
+    assert!(!fully_funded);
+    assert_eq!(status["required"], "4");
+    assert_eq!(status["shortfall"], "2");
+
+    let res = keypom_contract
+        .view("get_drop_funding_status")
+        .args_json(json!({ "drop_id": "missing_drop" }))
+        .await;
+    assert!(format!("{:?}", res.unwrap_err()).contains("Drop not found"));
+
+    println!("      Passed ✅ test_drop_funding");
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use crate::*;
+
+/// Drops created without an ID should be assigned one from the funder's counter, skipping IDs that are
+/// Already taken. Invalid or duplicate IDs should be rejected before anything is created.
+pub async fn test_auto_drop_ids(worker: &Worker<Sandbox>, owner: &Account) -> anyhow::Result<()> {
+    let keypom_contract = deploy_keypom(worker, owner).await?;
+    let funder = create_account(owner, "ids-funder", NearToken::from_near(20)).await?;
+
+    let empty_drop = |drop_id: Option<String>| {
+        json!({
+            "drop_id": drop_id,
+            "key_data": [],
+            "asset_data": [{ "uses": 1, "assets": [null] }]
+        })
+    };
+
+    let res = funder
+        .call(keypom_contract.id(), "create_drop")
+        .args_json(empty_drop(None))
+        .deposit(NearToken::from_near(1))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+    assert_eq!(res.json::<String>()?, format!("{}-0", funder.id()));
+
+    // Take the next ID in the counter by hand
+    let res = funder
+        .call(keypom_contract.id(), "create_drop")
+        .args_json(empty_drop(Some(format!("{}-1", funder.id()))))
+        .deposit(NearToken::from_near(1))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+
+    // IDs can't contain colons or be reused
+    let res = funder
+        .call(keypom_contract.id(), "create_drop")
+        .args_json(empty_drop(Some("bad:drop".to_string())))
+        .deposit(NearToken::from_near(1))
+        .transact()
+        .await?;
+    assert_failed_with(res, "Drop ID cannot contain a colon (:)");
+    let res = funder
+        .call(keypom_contract.id(), "create_drop")
+        .args_json(empty_drop(Some(format!("{}-0", funder.id()))))
+        .deposit(NearToken::from_near(1))
+        .transact()
+        .await?;
+    assert_failed_with(res, "already exists");
+
+    // A batch with a duplicate ID creates nothing
+    let asset_data = json!([{ "uses": 1, "assets": [null] }]);
+    let res = funder
+        .call(keypom_contract.id(), "create_drop_batch")
+        .args_json(json!({
+            "drop_ids": [null, "dup_drop", "dup_drop"],
+            "asset_datas": [asset_data, asset_data, asset_data],
+            "drop_configs": [null, null, null]
+        }))
+        .deposit(NearToken::from_near(3))
+        .transact()
+        .await?;
+    assert_failed_with(res, "Drop ID dup_drop is used more than once in the batch");
+
+    // Assigned IDs skip the one that was taken by hand
+    let res = funder
+        .call(keypom_contract.id(), "create_drop_batch")
+        .args_json(json!({
+            "drop_ids": [null, null],
+            "asset_datas": [asset_data, asset_data],
+            "drop_configs": [null, null]
+        }))
+        .deposit(NearToken::from_near(2))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+    let results = res.json::<serde_json::Value>()?;
+    assert_eq!(results[0]["drop_id"], format!("{}-2", funder.id()));
+    assert_eq!(results[1]["drop_id"], format!("{}-3", funder.id()));
+
+    let drops = keypom_contract
+        .view("get_drop_supply_for_funder")
+        .args_json(json!({ "account_id": funder.id() }))
+        .await?
+        .json::<u64>()?;
+    assert_eq!(drops, 4);
+
+    println!("      Passed ✅ test_auto_drop_ids");
+    Ok(())
+}
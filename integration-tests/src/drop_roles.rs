@@ -0,0 +1,88 @@
+use crate::*;
+
+/// Accounts given a role on a drop should only be able to perform that operation and any refunds from it
+/// Should still go to the funder
+pub async fn test_drop_roles(worker: &Worker<Sandbox>, owner: &Account) -> anyhow::Result<()> {
+    let keypom_contract = deploy_keypom(worker, owner).await?;
+    let funder = create_account(owner, "roles-funder", NearToken::from_near(20)).await?;
+    let deleter = create_account(owner, "roles-deleter", NearToken::from_near(5)).await?;
+
+    let keys = generate_keypairs(2);
+    let res = funder
+        .call(keypom_contract.id(), "create_drop")
+        .args_json(json!({
+            "drop_id": "team_drop",
+            "key_data": keys.iter().map(|k| json!({ "public_key": k.public_key() })).collect::<Vec<_>>(),
+            "asset_data": [{ "uses": 1, "assets": [{ "yoctonear": NearToken::from_near(1).as_yoctonear().to_string() }] }]
+        }))
+        .deposit(NearToken::from_near(5))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+
+    // Nobody but the funder can manage the drop until roles are handed out
+    let res = deleter
+        .call(keypom_contract.id(), "delete_keys")
+        .args_json(json!({ "drop_id": "team_drop", "public_keys": [keys[0].public_key()] }))
+        .transact()
+        .await?;
+    assert_failed_with(res, "does not have permission to delete_keys");
+
+    // Only the funder can hand out roles
+    let res = deleter
+        .call(keypom_contract.id(), "set_drop_roles")
+        .args_json(json!({ "drop_id": "team_drop", "roles": { "deleters": [deleter.id()] } }))
+        .transact()
+        .await?;
+    assert_failed_with(res, "Only drop funder can set roles");
+
+    let res = funder
+        .call(keypom_contract.id(), "set_drop_roles")
+        .args_json(json!({ "drop_id": "team_drop", "roles": { "deleters": [deleter.id()] } }))
+        .deposit(NearToken::from_millinear(10))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+
+    // Deleters can't add keys
+    let res = deleter
+        .call(keypom_contract.id(), "add_keys")
+        .args_json(json!({
+            "drop_id": "team_drop",
+            "key_data": [{ "public_key": generate_keypairs(1)[0].public_key() }]
+        }))
+        .deposit(NearToken::from_near(2))
+        .transact()
+        .await?;
+    assert_failed_with(res, "does not have permission to add_keys");
+
+    // The deleted key is refunded to the funder rather than the deleter
+    let get_balance = |account: AccountId| {
+        let keypom_contract = keypom_contract.clone();
+        async move {
+            anyhow::Ok(
+                keypom_contract
+                    .view("get_user_balance")
+                    .args_json(json!({ "account_id": account }))
+                    .await?
+                    .json::<U128>()?
+                    .0,
+            )
+        }
+    };
+    let funder_balance = get_balance(funder.id().clone()).await?;
+    let res = deleter
+        .call(keypom_contract.id(), "delete_keys")
+        .args_json(json!({ "drop_id": "team_drop", "public_keys": [keys[0].public_key()] }))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+    assert!(
+        get_balance(funder.id().clone()).await?
+            > funder_balance + NearToken::from_near(1).as_yoctonear()
+    );
+    assert_eq!(get_balance(deleter.id().clone()).await?, 0);
+
+    println!("      Passed ✅ test_drop_roles");
+    Ok(())
+}
@@ -0,0 +1,110 @@
+use crate::*;
+
+/// Funders should be able to clone their drops and save them as templates that new drops are created from.
+/// Only the drop's shape is copied and nobody else can copy the drop.
+pub async fn test_drop_templates(worker: &Worker<Sandbox>, owner: &Account) -> anyhow::Result<()> {
+    let keypom_contract = deploy_keypom(worker, owner).await?;
+    let funder = create_account(owner, "template-funder", NearToken::from_near(30)).await?;
+    let outsider = create_account(owner, "template-outsider", NearToken::from_near(5)).await?;
+
+    let res = funder
+        .call(keypom_contract.id(), "create_drop")
+        .args_json(json!({
+            "drop_id": "weekly_drop",
+            "key_data": [{ "public_key": generate_keypairs(1)[0].public_key() }],
+            "asset_data": [
+                { "uses": 1, "assets": [{ "yoctonear": NearToken::from_millinear(100).as_yoctonear().to_string() }] },
+                { "uses": 1, "assets": [null], "config": { "permissions": "claim" } }
+            ],
+            "drop_config": { "metadata": "week 1" }
+        }))
+        .deposit(NearToken::from_near(1))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+
+    let get_drop = |drop_id: String| {
+        let keypom_contract = keypom_contract.clone();
+        async move {
+            keypom_contract
+                .view("get_drop_information")
+                .args_json(json!({ "drop_id": drop_id }))
+                .await?
+                .json::<serde_json::Value>()
+        }
+    };
+    let source_drop = get_drop("weekly_drop".to_string()).await?;
+
+    // Only the funder can copy their drop
+    let res = outsider
+        .call(keypom_contract.id(), "clone_drop")
+        .args_json(json!({ "source_drop_id": "weekly_drop", "new_drop_id": "stolen_drop" }))
+        .deposit(NearToken::from_near(1))
+        .transact()
+        .await?;
+    assert_failed_with(res, "Only drop funder can copy the drop");
+
+    // The clone has the same shape with its own keys and metadata
+    let res = funder
+        .call(keypom_contract.id(), "clone_drop")
+        .args_json(json!({
+            "source_drop_id": "weekly_drop",
+            "new_drop_id": "week_2_drop",
+            "overrides": {
+                "key_data": [{ "public_key": generate_keypairs(1)[0].public_key() }],
+                "metadata": "week 2"
+            }
+        }))
+        .deposit(NearToken::from_near(1))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+    assert_eq!(res.json::<String>()?, "week_2_drop");
+    let cloned_drop = get_drop("week_2_drop".to_string()).await?;
+    assert_eq!(cloned_drop["asset_data"], source_drop["asset_data"]);
+    assert_eq!(cloned_drop["drop_config"]["metadata"], "week 2");
+    assert_eq!(cloned_drop["next_key_id"], 1);
+
+    // Templates are listed for the funder and can be used until they're deleted
+    let res = funder
+        .call(keypom_contract.id(), "save_drop_template")
+        .args_json(json!({ "template_name": "weekly", "source_drop_id": "weekly_drop" }))
+        .deposit(NearToken::from_millinear(100))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+    let templates = keypom_contract
+        .view("get_drop_templates_for_funder")
+        .args_json(json!({ "funder_id": funder.id() }))
+        .await?
+        .json::<serde_json::Value>()?;
+    assert_eq!(templates[0]["template_name"], "weekly");
+    assert_eq!(templates[0]["asset_data"], source_drop["asset_data"]);
+
+    let res = funder
+        .call(keypom_contract.id(), "create_drop_from_template")
+        .args_json(json!({ "template_name": "weekly", "new_drop_id": "week_3_drop" }))
+        .deposit(NearToken::from_near(1))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+    let templated_drop = get_drop("week_3_drop".to_string()).await?;
+    assert_eq!(templated_drop["drop_config"]["metadata"], "week 1");
+
+    let res = funder
+        .call(keypom_contract.id(), "delete_drop_template")
+        .args_json(json!({ "template_name": "weekly" }))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+    let res = funder
+        .call(keypom_contract.id(), "create_drop_from_template")
+        .args_json(json!({ "template_name": "weekly", "new_drop_id": "week_4_drop" }))
+        .deposit(NearToken::from_near(1))
+        .transact()
+        .await?;
+    assert_failed_with(res, "Template not found");
+
+    println!("      Passed ✅ test_drop_templates");
+    Ok(())
+}
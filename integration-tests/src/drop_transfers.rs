@@ -0,0 +1,99 @@
+use crate::*;
+
+/// Drops should only change hands once the account they were offered to accepts them. The new funder then
+/// Manages the drop and receives its refunds while the old funder loses access.
+pub async fn test_drop_transfers(worker: &Worker<Sandbox>, owner: &Account) -> anyhow::Result<()> {
+    let keypom_contract = deploy_keypom(worker, owner).await?;
+    let funder = create_account(owner, "transfer-old-funder", NearToken::from_near(20)).await?;
+    let new_funder = create_account(owner, "transfer-new-funder", NearToken::from_near(5)).await?;
+    let outsider = create_account(owner, "transfer-outsider", NearToken::from_near(5)).await?;
+
+    let keys = generate_keypairs(1);
+    let res = funder
+        .call(keypom_contract.id(), "create_drop")
+        .args_json(json!({
+            "drop_id": "handover_drop",
+            "key_data": [{ "public_key": keys[0].public_key() }],
+            "asset_data": [{ "uses": 1, "assets": [{ "yoctonear": NearToken::from_near(1).as_yoctonear().to_string() }] }]
+        }))
+        .deposit(NearToken::from_near(2))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+
+    // Drops can't be taken without an offer
+    let res = new_funder
+        .call(keypom_contract.id(), "accept_drop_transfer")
+        .args_json(json!({ "drop_id": "handover_drop" }))
+        .transact()
+        .await?;
+    assert_failed_with(res, "No pending transfer for drop");
+
+    let res = funder
+        .call(keypom_contract.id(), "offer_drop_transfer")
+        .args_json(json!({ "drop_id": "handover_drop", "new_funder_id": new_funder.id() }))
+        .deposit(NearToken::from_millinear(10))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+    let drop = keypom_contract
+        .view("get_drop_information")
+        .args_json(json!({ "drop_id": "handover_drop" }))
+        .await?
+        .json::<serde_json::Value>()?;
+    assert_eq!(drop["pending_funder_id"], json!(new_funder.id()));
+
+    // Only the account the drop was offered to can accept it
+    let res = outsider
+        .call(keypom_contract.id(), "accept_drop_transfer")
+        .args_json(json!({ "drop_id": "handover_drop" }))
+        .transact()
+        .await?;
+    assert_failed_with(res, "Drop was not offered to the predecessor");
+
+    let res = new_funder
+        .call(keypom_contract.id(), "accept_drop_transfer")
+        .args_json(json!({ "drop_id": "handover_drop" }))
+        .deposit(NearToken::from_millinear(10))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+    assert!(res.logs().iter().any(|log| log.contains("drop_transfer")));
+
+    let drop = keypom_contract
+        .view("get_drop_information")
+        .args_json(json!({ "drop_id": "handover_drop" }))
+        .await?
+        .json::<serde_json::Value>()?;
+    assert_eq!(drop["funder_id"], json!(new_funder.id()));
+    assert!(drop["pending_funder_id"].is_null());
+    let drops = keypom_contract
+        .view("get_drops_for_funder")
+        .args_json(json!({ "account_id": new_funder.id() }))
+        .await?
+        .json::<serde_json::Value>()?;
+    assert_eq!(drops[0]["drop_id"], "handover_drop");
+
+    // The old funder can no longer manage the drop and refunds go to the new funder
+    let res = funder
+        .call(keypom_contract.id(), "delete_keys")
+        .args_json(json!({ "drop_id": "handover_drop" }))
+        .transact()
+        .await?;
+    assert_failed_with(res, "does not have permission to delete_keys");
+    let res = new_funder
+        .call(keypom_contract.id(), "delete_keys")
+        .args_json(json!({ "drop_id": "handover_drop" }))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+    let balance = keypom_contract
+        .view("get_user_balance")
+        .args_json(json!({ "account_id": new_funder.id() }))
+        .await?
+        .json::<U128>()?;
+    assert!(balance.0 > NearToken::from_near(1).as_yoctonear());
+
+    println!("      Passed ✅ test_drop_transfers");
+    Ok(())
+}
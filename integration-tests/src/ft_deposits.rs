@@ -0,0 +1,188 @@
+use crate::*;
+
+/// FTs sent to a drop by anyone other than the funder should only be accepted from allowed senders who can cover
+/// The storage to track them. Contributors can reclaim their deposit except for the tokens the remaining uses need
+/// And the funder can't withdraw it.
+pub async fn test_ft_deposits(worker: &Worker<Sandbox>, owner: &Account) -> anyhow::Result<()> {
+    let keypom_contract = deploy_keypom(worker, owner).await?;
+    let wrapped_near = deploy_wrapped_near(worker).await?;
+    let funder = create_account(owner, "deposits-funder", NearToken::from_near(20)).await?;
+    let contributor =
+        create_account(owner, "deposits-contributor", NearToken::from_near(10)).await?;
+    let stranger = create_account(owner, "deposits-stranger", NearToken::from_near(10)).await?;
+    mint_wrapped_near(
+        &wrapped_near,
+        keypom_contract.as_account(),
+        NearToken::from_near(0),
+    )
+    .await?;
+    for account in [&funder, &contributor, &stranger] {
+        mint_wrapped_near(&wrapped_near, account, NearToken::from_near(5)).await?;
+    }
+
+    let ft_amount = NearToken::from_near(1).as_yoctonear();
+    let res = funder
+        .call(keypom_contract.id(), "create_drop")
+        .args_json(json!({
+            "drop_id": "ft_drop",
+            "key_data": [{ "public_key": generate_keypairs(1)[0].public_key() }],
+            "asset_data": [{
+                "uses": 1,
+                "assets": [{
+                    "ft_contract_id": wrapped_near.id(),
+                    "registration_cost": NearToken::from_millinear(125).as_yoctonear().to_string(),
+                    "ft_amount": ft_amount.to_string()
+                }]
+            }],
+            "drop_config": { "ft_deposit_config": { "allowed_senders": [contributor.id()] } }
+        }))
+        .deposit(NearToken::from_near(2))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+
+    let send_ft = |sender: Account, amount: u128| {
+        let wrapped_near = wrapped_near.clone();
+        let keypom_id = keypom_contract.id().clone();
+        async move {
+            sender
+                .call(wrapped_near.id(), "ft_transfer_call")
+                .args_json(json!({
+                    "receiver_id": keypom_id,
+                    "amount": amount.to_string(),
+                    "msg": "ft_drop"
+                }))
+                .deposit(NearToken::from_yoctonear(1))
+                .gas(NearGas::from_tgas(100))
+                .transact()
+                .await
+        }
+    };
+    let ft_balance = |account_id: AccountId| {
+        let wrapped_near = wrapped_near.clone();
+        async move {
+            anyhow::Ok(
+                wrapped_near
+                    .view("ft_balance_of")
+                    .args_json(json!({ "account_id": account_id }))
+                    .await?
+                    .json::<U128>()?
+                    .0,
+            )
+        }
+    };
+    let get_deposit = || {
+        let keypom_contract = keypom_contract.clone();
+        let contributor_id = contributor.id().clone();
+        let wrapped_near_id = wrapped_near.id().clone();
+        async move {
+            anyhow::Ok(
+                keypom_contract
+                    .view("get_ft_deposit")
+                    .args_json(json!({
+                        "drop_id": "ft_drop",
+                        "sender_id": contributor_id,
+                        "ft_contract_id": wrapped_near_id
+                    }))
+                    .await?
+                    .json::<U128>()?
+                    .0,
+            )
+        }
+    };
+    let get_balance = |account_id: AccountId| {
+        let keypom_contract = keypom_contract.clone();
+        async move {
+            anyhow::Ok(
+                keypom_contract
+                    .view("get_user_balance")
+                    .args_json(json!({ "account_id": account_id }))
+                    .await?
+                    .json::<U128>()?
+                    .0,
+            )
+        }
+    };
+
+    // Senders that aren't allowed get all their tokens back
+    let stranger_balance = ft_balance(stranger.id().clone()).await?;
+    assert!(send_ft(stranger.clone(), ft_amount).await?.is_success());
+    assert_eq!(ft_balance(stranger.id().clone()).await?, stranger_balance);
+
+    // Contributors pay for the storage to track their deposit so tokens are returned if they have no balance
+    let contributor_ft_balance = ft_balance(contributor.id().clone()).await?;
+    assert!(send_ft(contributor.clone(), 2 * ft_amount)
+        .await?
+        .is_success());
+    assert_eq!(
+        ft_balance(contributor.id().clone()).await?,
+        contributor_ft_balance
+    );
+    assert_eq!(get_deposit().await?, 0);
+
+    let res = contributor
+        .call(keypom_contract.id(), "add_to_balance")
+        .deposit(NearToken::from_millinear(100))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+    let funder_balance = get_balance(funder.id().clone()).await?;
+    assert!(send_ft(contributor.clone(), 2 * ft_amount)
+        .await?
+        .is_success());
+    assert_eq!(get_deposit().await?, 2 * ft_amount);
+    assert!(
+        get_balance(contributor.id().clone()).await?
+            < NearToken::from_millinear(100).as_yoctonear()
+    );
+    assert_eq!(get_balance(funder.id().clone()).await?, funder_balance);
+
+    // The funder can't withdraw what contributors deposited
+    let res = funder
+        .call(keypom_contract.id(), "withdraw_ft_balance")
+        .args_json(json!({
+            "drop_id": "ft_drop",
+            "ft_contract_id": wrapped_near.id(),
+            "tokens_to_withdraw": ft_amount.to_string()
+        }))
+        .gas(NearGas::from_tgas(100))
+        .transact()
+        .await?;
+    assert_failed_with(res, "were deposited by contributors");
+
+    // Only the tokens the key doesn't need can be reclaimed
+    let reclaim = || {
+        let contributor = contributor.clone();
+        let keypom_id = keypom_contract.id().clone();
+        let wrapped_near_id = wrapped_near.id().clone();
+        async move {
+            contributor
+                .call(&keypom_id, "reclaim_ft_deposit")
+                .args_json(json!({ "drop_id": "ft_drop", "ft_contract_id": wrapped_near_id }))
+                .gas(NearGas::from_tgas(100))
+                .transact()
+                .await
+        }
+    };
+    let res = reclaim().await?;
+    assert!(res.is_success());
+    assert_eq!(
+        ft_balance(contributor.id().clone()).await?,
+        contributor_ft_balance - ft_amount
+    );
+    assert_eq!(get_deposit().await?, ft_amount);
+    assert_failed_with(reclaim().await?, "No tokens left to reclaim");
+
+    // Once the funder covers the key, the rest of the deposit can be reclaimed
+    assert!(send_ft(funder.clone(), ft_amount).await?.is_success());
+    let res = reclaim().await?;
+    assert!(res.is_success());
+    assert_eq!(get_deposit().await?, 0);
+    assert_eq!(
+        ft_balance(contributor.id().clone()).await?,
+        contributor_ft_balance
+    );
+
+    println!("      Passed ✅ test_ft_deposits");
+    Ok(())
+}
@@ -1,7 +1,9 @@
 use crate::*;
 use models::*;
+use near_workspaces::network::Sandbox;
+use near_workspaces::result::ExecutionFinalResult;
 use near_workspaces::types::{KeyType, PublicKey, SecretKey};
-use near_workspaces::Contract;
+use near_workspaces::{Account, AccountId, Contract, Worker};
 
 pub fn sign_kp_message(sk: &near_crypto::SecretKey, nonce: u32, message: &String) -> Base64VecU8 {
     let signature = match sk.sign(&format!("{}{}", message, nonce).as_bytes()) {
@@ -79,3 +81,145 @@ pub fn generate_keypairs(num_keys: u16) -> Vec<SecretKey> {
 
     sks
 }
+
+/// Deploy and initialize a fresh Keypom contract so each test starts from a clean state
+pub async fn deploy_keypom(worker: &Worker<Sandbox>, owner: &Account) -> anyhow::Result<Contract> {
+    let keypom_wasm = std::fs::read(KEYPOM_WASM_PATH)?;
+    let keypom_contract = worker.dev_deploy(&keypom_wasm).await?;
+    keypom_contract
+        .call("new")
+        .args_json(json!({
+            "root_account": owner.id(),
+            "owner_id": owner.id(),
+        }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    Ok(keypom_contract)
+}
+
+/// Create a sub-account of `parent` with the given starting balance
+pub async fn create_account(
+    parent: &Account,
+    name: &str,
+    balance: NearToken,
+) -> anyhow::Result<Account> {
+    let account = parent
+        .create_subaccount(name)
+        .initial_balance(balance)
+        .transact()
+        .await?
+        .into_result()?;
+
+    Ok(account)
+}
+
+/// Sign a transaction with a drop key to call one of Keypom's claim methods.
+/// The gas the key requires is attached along with any extra gas that's needed.
+pub async fn claim_with_key(
+    contract: &Contract,
+    sk: &SecretKey,
+    method: &str,
+    args: serde_json::Value,
+    extra_gas: NearGas,
+) -> anyhow::Result<ExecutionFinalResult> {
+    let required_gas = get_required_gas(contract, sk.public_key()).await?;
+    let mut key_account = contract.as_account().clone();
+    key_account.set_secret_key(sk.clone());
+
+    let res = key_account
+        .call(contract.id(), method)
+        .args_json(args)
+        .gas(NearGas::from_gas(required_gas + extra_gas.as_gas()))
+        .transact()
+        .await?;
+
+    Ok(res)
+}
+
+/// Get how much gas a key needs to be used
+pub async fn get_required_gas(contract: &Contract, key: PublicKey) -> anyhow::Result<u64> {
+    let key_info = contract
+        .view("get_key_information")
+        .args_json(json!({ "key": key }))
+        .await?
+        .json::<serde_json::Value>()?;
+
+    Ok(key_info["required_gas"].as_str().unwrap().parse::<u64>()?)
+}
+
+/// Get the NEAR-implicit account ID for an ED25519 public key
+pub fn implicit_account_id(pk: &PublicKey) -> AccountId {
+    pk.key_data()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect::<String>()
+        .parse()
+        .unwrap()
+}
+
+/// Assert that a transaction failed with an error containing `expected`
+pub fn assert_failed_with(res: ExecutionFinalResult, expected: &str) {
+    let err = res
+        .into_result()
+        .expect_err("Transaction should have failed");
+    assert!(
+        format!("{err:?}").contains(expected),
+        "Expected error containing {:?} but got {:?}",
+        expected,
+        err
+    );
+}
+
+/// Import the mainnet linkdrop contract so `create_account_and_claim` has a root account that can create accounts.
+/// New accounts will be sub-accounts of the returned account
+pub async fn deploy_linkdrop(worker: &Worker<Sandbox>) -> anyhow::Result<Contract> {
+    let mainnet = near_workspaces::mainnet().await?;
+    let linkdrop = worker
+        .import_contract(&"near".parse()?, &mainnet)
+        .dest_account_id(&"linkdrop.test.near".parse()?)
+        .initial_balance(NearToken::from_near(50))
+        .transact()
+        .await?;
+
+    Ok(linkdrop)
+}
+
+/// Import the mainnet wrapped NEAR contract so drops have a fungible token to hold
+pub async fn deploy_wrapped_near(worker: &Worker<Sandbox>) -> anyhow::Result<Contract> {
+    let mainnet = near_workspaces::mainnet().await?;
+    let wrapped_near = worker
+        .import_contract(&"wrap.near".parse()?, &mainnet)
+        .initial_balance(NearToken::from_near(50))
+        .transact()
+        .await?;
+    wrapped_near.call("new").transact().await?.into_result()?;
+
+    Ok(wrapped_near)
+}
+
+/// Register `account` with the wrapped NEAR contract and wrap `amount` of their $NEAR
+pub async fn mint_wrapped_near(
+    wrapped_near: &Contract,
+    account: &Account,
+    amount: NearToken,
+) -> anyhow::Result<()> {
+    account
+        .call(wrapped_near.id(), "storage_deposit")
+        .args_json(json!({}))
+        .deposit(NearToken::from_millinear(125))
+        .transact()
+        .await?
+        .into_result()?;
+    if amount.as_yoctonear() > 0 {
+        account
+            .call(wrapped_near.id(), "near_deposit")
+            .deposit(amount)
+            .transact()
+            .await?
+            .into_result()?;
+    }
+
+    Ok(())
+}
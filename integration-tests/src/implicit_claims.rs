@@ -0,0 +1,94 @@
+use crate::*;
+
+/// Claiming to implicit accounts should send the assets to the account derived from the new public key.
+/// If the use doesn't send any $NEAR, the funder's balance covers the transfer that creates the account
+pub async fn test_implicit_claims(worker: &Worker<Sandbox>, owner: &Account) -> anyhow::Result<()> {
+    let keypom_contract = deploy_keypom(worker, owner).await?;
+    let funder = create_account(owner, "implicit-funder", NearToken::from_near(20)).await?;
+
+    let keys = generate_keypairs(2);
+    let res = funder
+        .call(keypom_contract.id(), "create_drop")
+        .args_json(json!({
+            "drop_id": "implicit_drop",
+            "key_data": [{ "public_key": keys[0].public_key() }],
+            "asset_data": [{
+                "uses": 1,
+                "assets": [{ "yoctonear": NearToken::from_near(1).as_yoctonear().to_string() }]
+            }]
+        }))
+        .deposit(NearToken::from_near(3))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+
+    // The assets should land in the NEAR-implicit account for the new public key
+    let new_key = generate_keypairs(1).remove(0);
+    let implicit_id = implicit_account_id(&new_key.public_key());
+    let res = claim_with_key(
+        &keypom_contract,
+        &keys[0],
+        "create_implicit_account_and_claim",
+        json!({ "new_public_key": new_key.public_key() }),
+        NearGas::from_tgas(0),
+    )
+    .await?;
+    assert!(res.is_success());
+    let implicit_account = worker.view_account(&implicit_id).await?;
+    assert!(implicit_account.balance >= NearToken::from_near(1));
+
+    // Naming rules don't apply to implicit accounts and uses without $NEAR are funded by the funder's balance
+    let res = funder
+        .call(keypom_contract.id(), "create_drop")
+        .args_json(json!({
+            "drop_id": "named_drop",
+            "key_data": [{ "public_key": keys[1].public_key() }],
+            "asset_data": [{
+                "uses": 1,
+                "assets": [null],
+                "config": { "account_name_rules": { "prefix": "keypom-" } }
+            }]
+        }))
+        .deposit(NearToken::from_near(1))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+
+    let new_key = generate_keypairs(1).remove(0);
+    let res = claim_with_key(
+        &keypom_contract,
+        &keys[1],
+        "create_implicit_account_and_claim",
+        json!({ "new_public_key": new_key.public_key() }),
+        NearGas::from_tgas(0),
+    )
+    .await?;
+    assert_failed_with(res, "is less than required");
+    assert!(worker
+        .view_account(&implicit_account_id(&new_key.public_key()))
+        .await
+        .is_err());
+
+    let res = funder
+        .call(keypom_contract.id(), "add_to_balance")
+        .deposit(NearToken::from_near(1))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+    let res = claim_with_key(
+        &keypom_contract,
+        &keys[1],
+        "create_implicit_account_and_claim",
+        json!({ "new_public_key": new_key.public_key() }),
+        NearGas::from_tgas(0),
+    )
+    .await?;
+    assert!(res.is_success());
+    assert!(worker
+        .view_account(&implicit_account_id(&new_key.public_key()))
+        .await
+        .is_ok());
+
+    println!("      Passed ✅ test_implicit_claims");
+    Ok(())
+}
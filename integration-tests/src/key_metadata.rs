@@ -0,0 +1,118 @@
+use crate::*;
+
+/// NFT keys should render the drop's metadata template with each key's info, let a key's own
+/// Token metadata take precedence over the template and show the drop's collection metadata
+pub async fn test_key_token_metadata(
+    worker: &Worker<Sandbox>,
+    owner: &Account,
+) -> anyhow::Result<()> {
+    let keypom_contract = deploy_keypom(worker, owner).await?;
+    let funder = create_account(owner, "metadata-funder", NearToken::from_near(20)).await?;
+
+    let keys = generate_keypairs(3);
+    let res = funder
+        .call(keypom_contract.id(), "create_drop")
+        .args_json(json!({
+            "drop_id": "ticket_drop",
+            "key_data": [
+                { "public_key": keys[0].public_key(), "metadata": "A1", "key_owner": funder.id() },
+                { "public_key": keys[1].public_key(), "token_metadata": { "title": "VIP" } },
+                { "public_key": keys[2].public_key() }
+            ],
+            "asset_data": [{ "uses": 2, "assets": [null] }],
+            "drop_config": {
+                "nft_keys_config": {
+                    "token_metadata": {
+                        "title": "Ticket {token_id}",
+                        "description": "Seat {key_metadata} in {drop_id} ({remaining_uses} uses left)"
+                    }
+                }
+            }
+        }))
+        .deposit(NearToken::from_near(2))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+
+    let get_metadata = |token_id: &'static str| {
+        let keypom_contract = keypom_contract.clone();
+        async move {
+            anyhow::Ok(
+                keypom_contract
+                    .view("nft_token")
+                    .args_json(json!({ "token_id": token_id }))
+                    .await?
+                    .json::<serde_json::Value>()?["metadata"]
+                    .clone(),
+            )
+        }
+    };
+
+    // The template is filled in with the key's own info
+    let metadata = get_metadata("ticket_drop:0").await?;
+    assert_eq!(metadata["title"], "Ticket ticket_drop:0");
+    assert_eq!(
+        metadata["description"],
+        "Seat A1 in ticket_drop (2 uses left)"
+    );
+
+    // Key level metadata overrides the template entirely
+    let metadata = get_metadata("ticket_drop:1").await?;
+    assert_eq!(metadata["title"], "VIP");
+    assert!(metadata["description"].is_null());
+
+    // Keys without metadata leave that placeholder empty
+    let metadata = get_metadata("ticket_drop:2").await?;
+    assert_eq!(
+        metadata["description"],
+        "Seat  in ticket_drop (2 uses left)"
+    );
+
+    // The enumeration methods render the metadata the same way
+    let tokens = keypom_contract
+        .view("nft_tokens_for_owner")
+        .args_json(json!({ "account_id": funder.id() }))
+        .await?
+        .json::<serde_json::Value>()?;
+    assert_eq!(tokens[0]["metadata"]["title"], "Ticket ticket_drop:0");
+
+    // Tokens that don't exist have no metadata to render
+    let token = keypom_contract
+        .view("nft_token")
+        .args_json(json!({ "token_id": "ticket_drop:5" }))
+        .await?
+        .json::<serde_json::Value>()?;
+    assert!(token.is_null());
+
+    // Drops with their own collection metadata show it on every token
+    let collection_key = generate_keypairs(1).remove(0);
+    let res = funder
+        .call(keypom_contract.id(), "create_drop")
+        .args_json(json!({
+            "drop_id": "collection_drop",
+            "key_data": [{ "public_key": collection_key.public_key() }],
+            "asset_data": [{ "uses": 1, "assets": [null] }],
+            "drop_config": {
+                "nft_keys_config": {
+                    "token_metadata": { "media": "ticket.png" },
+                    "contract_metadata": {
+                        "spec": "nft-1.0.0",
+                        "name": "Event Tickets",
+                        "symbol": "TIX",
+                        "base_uri": "https://event.example/"
+                    }
+                }
+            }
+        }))
+        .deposit(NearToken::from_near(2))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+
+    let metadata = get_metadata("collection_drop:0").await?;
+    assert_eq!(metadata["title"], "Event Tickets");
+    assert_eq!(metadata["media"], "https://event.example/ticket.png");
+
+    println!("      Passed ✅ test_key_token_metadata");
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use crate::*;
+
+/// Multi-use NFT keys should be splittable into new keys owned by the same account and mergeable back into
+/// One key, with mint and burn events for the keys that come and go
+pub async fn test_key_splitting(worker: &Worker<Sandbox>, owner: &Account) -> anyhow::Result<()> {
+    let keypom_contract = deploy_keypom(worker, owner).await?;
+    let funder = create_account(owner, "split-funder", NearToken::from_near(20)).await?;
+    let holder = create_account(owner, "split-holder", NearToken::from_near(5)).await?;
+    let other = create_account(owner, "split-other", NearToken::from_near(5)).await?;
+
+    let keys = generate_keypairs(2);
+    let res = funder
+        .call(keypom_contract.id(), "create_drop")
+        .args_json(json!({
+            "drop_id": "family_drop",
+            "key_data": [
+                { "public_key": keys[0].public_key(), "key_owner": holder.id() },
+                { "public_key": keys[1].public_key(), "key_owner": other.id() }
+            ],
+            "asset_data": [{ "uses": 4, "assets": [null] }]
+        }))
+        .deposit(NearToken::from_near(2))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+
+    let get_uses = |key: String| {
+        let keypom_contract = keypom_contract.clone();
+        async move {
+            anyhow::Ok(
+                keypom_contract
+                    .view("get_key_information")
+                    .args_json(json!({ "key": key }))
+                    .await?
+                    .json::<serde_json::Value>()?["uses_remaining"]
+                    .as_u64()
+                    .unwrap(),
+            )
+        }
+    };
+
+    // The original key has to keep at least one use
+    let res = holder
+        .call(keypom_contract.id(), "split_key")
+        .args_json(json!({
+            "token_id": "family_drop:0",
+            "new_public_keys": generate_keypairs(2).iter().map(|k| k.public_key()).collect::<Vec<_>>(),
+            "uses_each": 2
+        }))
+        .deposit(NearToken::from_millinear(100))
+        .transact()
+        .await?;
+    assert_failed_with(res, "Key does not have enough uses to split");
+
+    // Only the owner can split their key
+    let res = other
+        .call(keypom_contract.id(), "split_key")
+        .args_json(json!({
+            "token_id": "family_drop:0",
+            "new_public_keys": [generate_keypairs(1)[0].public_key()],
+            "uses_each": 1
+        }))
+        .deposit(NearToken::from_millinear(100))
+        .transact()
+        .await?;
+    assert_failed_with(res, "Only the key owner can split keys");
+
+    let new_keys = generate_keypairs(2);
+    let res = holder
+        .call(keypom_contract.id(), "split_key")
+        .args_json(json!({
+            "token_id": "family_drop:0",
+            "new_public_keys": new_keys.iter().map(|k| k.public_key()).collect::<Vec<_>>(),
+            "uses_each": 1
+        }))
+        .deposit(NearToken::from_millinear(100))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+    assert!(res.logs().iter().any(|log| log.contains("nft_mint")));
+    assert!(res
+        .logs()
+        .iter()
+        .any(|log| log.contains("key_uses_transfer")));
+    assert_eq!(get_uses("family_drop:0".to_string()).await?, 2);
+    assert_eq!(get_uses("family_drop:2".to_string()).await?, 1);
+    assert_eq!(get_uses("family_drop:3".to_string()).await?, 1);
+
+    // The split keys can be claimed on their own
+    let res = claim_with_key(
+        &keypom_contract,
+        &new_keys[0],
+        "claim",
+        json!({ "account_id": holder.id() }),
+        NearGas::from_tgas(0),
+    )
+    .await?;
+    assert!(res.is_success());
+
+    // Keys owned by someone else can't be merged in
+    let res = holder
+        .call(keypom_contract.id(), "merge_keys")
+        .args_json(json!({ "token_ids": ["family_drop:0", "family_drop:1"] }))
+        .transact()
+        .await?;
+    assert_failed_with(res, "Keys must have the same owner");
+
+    // Merging the leftover key back burns it and gives its uses to the original key
+    let res = holder
+        .call(keypom_contract.id(), "merge_keys")
+        .args_json(json!({ "token_ids": ["family_drop:0", "family_drop:3"] }))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+    assert!(res.logs().iter().any(|log| log.contains("nft_burn")));
+    assert!(res
+        .logs()
+        .iter()
+        .any(|log| log.contains("key_uses_transfer")));
+    assert_eq!(get_uses("family_drop:0".to_string()).await?, 3);
+    assert!(keypom_contract
+        .view("get_key_information")
+        .args_json(json!({ "key": new_keys[1].public_key() }))
+        .await
+        .is_err());
+
+    println!("      Passed ✅ test_key_splitting");
+    Ok(())
+}
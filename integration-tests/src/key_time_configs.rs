@@ -0,0 +1,78 @@
+use crate::*;
+
+/// Keys with their own time config should follow it instead of the use's config while every other key in the
+/// Drop keeps the drop wide behaviour. Invalid key time configs are rejected when the keys are added.
+pub async fn test_key_time_configs(
+    worker: &Worker<Sandbox>,
+    owner: &Account,
+) -> anyhow::Result<()> {
+    let keypom_contract = deploy_keypom(worker, owner).await?;
+    let funder = create_account(owner, "time-funder", NearToken::from_near(20)).await?;
+    let claimer = create_account(owner, "time-claimer", NearToken::from_near(5)).await?;
+
+    let now = worker.view_block().await?.timestamp();
+    let one_hour = 3_600 * 1_000_000_000u64;
+
+    // Keys can't have a time config that has already ended
+    let res = funder
+        .call(keypom_contract.id(), "create_drop")
+        .args_json(json!({
+            "drop_id": "invalid_drop",
+            "key_data": [{ "public_key": generate_keypairs(1)[0].public_key(), "time_config": { "end": now - one_hour } }],
+            "asset_data": [{ "uses": 1, "assets": [null] }]
+        }))
+        .deposit(NearToken::from_near(1))
+        .transact()
+        .await?;
+    assert_failed_with(
+        res,
+        "The end timestamp must be greater than the current block timestamp",
+    );
+
+    // The VIP key opens immediately while everyone else has to wait an hour
+    let keys = generate_keypairs(2);
+    let res = funder
+        .call(keypom_contract.id(), "create_drop")
+        .args_json(json!({
+            "drop_id": "staggered_drop",
+            "key_data": [
+                { "public_key": keys[0].public_key(), "time_config": { "end": now + 24 * one_hour } },
+                { "public_key": keys[1].public_key() }
+            ],
+            "asset_data": [{ "uses": 1, "assets": [null], "config": { "time": { "start": now + one_hour } } }]
+        }))
+        .deposit(NearToken::from_near(1))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+
+    let key_info = keypom_contract
+        .view("get_key_information")
+        .args_json(json!({ "key": keys[0].public_key() }))
+        .await?
+        .json::<serde_json::Value>()?;
+    assert_eq!(key_info["time_config"]["end"], json!(now + 24 * one_hour));
+
+    let res = claim_with_key(
+        &keypom_contract,
+        &keys[1],
+        "claim",
+        json!({ "account_id": claimer.id() }),
+        NearGas::from_tgas(0),
+    )
+    .await?;
+    assert_failed_with(res, "isn't claimable until");
+
+    let res = claim_with_key(
+        &keypom_contract,
+        &keys[0],
+        "claim",
+        json!({ "account_id": claimer.id() }),
+        NearGas::from_tgas(0),
+    )
+    .await?;
+    assert!(res.is_success());
+
+    println!("      Passed ✅ test_key_time_configs");
+    Ok(())
+}
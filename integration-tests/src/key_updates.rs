@@ -0,0 +1,99 @@
+use crate::*;
+
+/// Funders should be able to correct key metadata and re-issue (or remove) key passwords, with a key update
+/// Event for every key. Nobody else can change the keys.
+pub async fn test_key_updates(worker: &Worker<Sandbox>, owner: &Account) -> anyhow::Result<()> {
+    let keypom_contract = deploy_keypom(worker, owner).await?;
+    let funder = create_account(owner, "update-funder", NearToken::from_near(20)).await?;
+    let claimer = create_account(owner, "update-claimer", NearToken::from_near(5)).await?;
+
+    let keys = generate_keypairs(2);
+    let res = funder
+        .call(keypom_contract.id(), "create_drop")
+        .args_json(json!({
+            "drop_id": "event_drop",
+            "key_data": keys.iter().map(|k| json!({ "public_key": k.public_key(), "metadata": "Alice" })).collect::<Vec<_>>(),
+            "asset_data": [{ "uses": 1, "assets": [null] }]
+        }))
+        .deposit(NearToken::from_near(1))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+
+    // Only the funder can update keys
+    let res = claimer
+        .call(keypom_contract.id(), "update_key_metadata")
+        .args_json(json!({ "drop_id": "event_drop", "metadata_by_token_id": { "event_drop:0": "Mallory" } }))
+        .transact()
+        .await?;
+    assert_failed_with(res, "Only drop funder can update keys");
+
+    let res = funder
+        .call(keypom_contract.id(), "update_key_metadata")
+        .args_json(json!({
+            "drop_id": "event_drop",
+            "metadata_by_token_id": { "event_drop:0": "Bob", "event_drop:1": null }
+        }))
+        .deposit(NearToken::from_millinear(10))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+    assert!(res.logs().iter().any(|log| log.contains("key_update")));
+
+    let get_key_info = |key: &'static str| {
+        let keypom_contract = keypom_contract.clone();
+        async move {
+            keypom_contract
+                .view("get_key_information")
+                .args_json(json!({ "key": key }))
+                .await?
+                .json::<serde_json::Value>()
+        }
+    };
+    assert_eq!(get_key_info("event_drop:0").await?["metadata"], "Bob");
+    assert!(get_key_info("event_drop:1").await?["metadata"].is_null());
+
+    // A leaked key gets a new password so it can't be claimed without it
+    let res = funder
+        .call(keypom_contract.id(), "update_key_passwords")
+        .args_json(json!({
+            "drop_id": "event_drop",
+            "password_by_use_by_token_id": { "event_drop:0": { "1": "deadbeef" } }
+        }))
+        .deposit(NearToken::from_millinear(10))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+    let res = claim_with_key(
+        &keypom_contract,
+        &keys[0],
+        "claim",
+        json!({ "account_id": claimer.id() }),
+        NearGas::from_tgas(0),
+    )
+    .await?;
+    assert_failed_with(res, "Password expected.");
+
+    // Removing the password makes the key claimable again
+    let res = funder
+        .call(keypom_contract.id(), "update_key_passwords")
+        .args_json(json!({
+            "drop_id": "event_drop",
+            "password_by_use_by_token_id": { "event_drop:0": null }
+        }))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+    let res = claim_with_key(
+        &keypom_contract,
+        &keys[0],
+        "claim",
+        json!({ "account_id": claimer.id() }),
+        NearGas::from_tgas(0),
+    )
+    .await?;
+    assert!(res.is_success());
+
+    println!("      Passed ✅ test_key_updates");
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use crate::*;
+
+/// Only the contract itself can migrate its state and only the owner can migrate drops. State and drops
+/// That are already in the current layout can't be migrated again.
+pub async fn test_migration(worker: &Worker<Sandbox>, owner: &Account) -> anyhow::Result<()> {
+    let keypom_contract = deploy_keypom(worker, owner).await?;
+    let funder = create_account(owner, "migration-funder", NearToken::from_near(10)).await?;
+
+    let keys = generate_keypairs(1);
+    let res = funder
+        .call(keypom_contract.id(), "create_drop")
+        .args_json(json!({
+            "drop_id": "current_drop",
+            "key_data": [{ "public_key": keys[0].public_key() }],
+            "asset_data": [{ "uses": 1, "assets": [null] }]
+        }))
+        .deposit(NearToken::from_near(1))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+
+    // Nobody but the contract can re-initialize it
+    let res = owner
+        .call(keypom_contract.id(), "migrate")
+        .args_json(json!({ "drop_ids": [] }))
+        .transact()
+        .await?;
+    assert_failed_with(res, "Method migrate is private");
+
+    // The state is already in the current layout so it can't be migrated again
+    let res = keypom_contract
+        .call("migrate")
+        .args_json(json!({ "drop_ids": ["current_drop"] }))
+        .transact()
+        .await?;
+    assert_failed_with(res, "Contract has already been migrated");
+
+    // Only the owner can migrate drops
+    let res = funder
+        .call(keypom_contract.id(), "migrate_drop")
+        .args_json(json!({ "drop_id": "current_drop" }))
+        .transact()
+        .await?;
+    assert_failed_with(res, "Only the contract owner can call this function");
+
+    // Drops created after the upgrade don't need to be migrated and can't be
+    let res = owner
+        .call(keypom_contract.id(), "migrate_drop")
+        .args_json(json!({ "drop_id": "current_drop" }))
+        .transact()
+        .await?;
+    assert_failed_with(res, "Drop has already been migrated");
+
+    // Nothing about the drop changed
+    let drop = keypom_contract
+        .view("get_drop_information")
+        .args_json(json!({ "drop_id": "current_drop" }))
+        .await?
+        .json::<serde_json::Value>()?;
+    assert_eq!(drop["funder_id"], json!(funder.id()));
+    assert_eq!(drop["max_key_uses"], 1);
+
+    println!("      Passed ✅ test_migration");
+    Ok(())
+}
@@ -0,0 +1,114 @@
+use crate::*;
+
+/// Transferring an NFT key with `nft_transfer_call` should notify the receiver and only give the key
+/// Back to the previous owner and public key if the receiver asks for it
+pub async fn test_nft_transfer_call(
+    worker: &Worker<Sandbox>,
+    owner: &Account,
+) -> anyhow::Result<()> {
+    let keypom_contract = deploy_keypom(worker, owner).await?;
+    // A second Keypom instance receives the keys since it accepts NFTs into its drops with `nft_on_transfer`
+    let receiver_contract = deploy_keypom(worker, owner).await?;
+    let funder = create_account(owner, "transfer-funder", NearToken::from_near(20)).await?;
+    let holder = create_account(owner, "transfer-holder", NearToken::from_near(10)).await?;
+
+    let keys = generate_keypairs(2);
+    let res = funder
+        .call(keypom_contract.id(), "create_drop")
+        .args_json(json!({
+            "drop_id": "nft_drop",
+            "key_data": keys.iter().map(|k| json!({ "public_key": k.public_key(), "key_owner": holder.id() })).collect::<Vec<_>>(),
+            "asset_data": [{ "uses": 1, "assets": [null] }]
+        }))
+        .deposit(NearToken::from_near(2))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+
+    // The holder sets up a drop on the receiver that their keys can be deposited into
+    let res = holder
+        .call(receiver_contract.id(), "create_drop")
+        .args_json(json!({
+            "drop_id": "escrow_drop",
+            "key_data": [],
+            "asset_data": [{ "uses": 1, "assets": [{ "nft_contract_id": keypom_contract.id() }] }]
+        }))
+        .deposit(NearToken::from_near(1))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+    let res = holder
+        .call(receiver_contract.id(), "add_to_balance")
+        .deposit(NearToken::from_near(1))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+
+    let get_token = |token_id: &'static str| {
+        let keypom_contract = keypom_contract.clone();
+        async move {
+            keypom_contract
+                .view("nft_token")
+                .args_json(json!({ "token_id": token_id }))
+                .await?
+                .json::<serde_json::Value>()
+        }
+    };
+
+    // The receiver keeps the key so it now owns it with the new public key
+    let new_key = generate_keypairs(1).remove(0);
+    let res = holder
+        .call(keypom_contract.id(), "nft_transfer_call")
+        .args_json(json!({
+            "receiver_id": receiver_contract.id(),
+            "token_id": "nft_drop:0",
+            "memo": new_key.public_key(),
+            "msg": "escrow_drop"
+        }))
+        .deposit(NearToken::from_yoctonear(1))
+        .gas(NearGas::from_tgas(100))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+    assert_eq!(res.json::<bool>()?, true);
+    assert_eq!(
+        get_token("nft_drop:0").await?["owner_id"],
+        json!(receiver_contract.id())
+    );
+    let key_info = keypom_contract
+        .view("get_key_information")
+        .args_json(json!({ "key": new_key.public_key() }))
+        .await?
+        .json::<serde_json::Value>()?;
+    assert_eq!(key_info["token_id"], "nft_drop:0");
+
+    // The receiver panics for a drop that doesn't exist so the key goes back to the holder and its old public key
+    let reverted_key = generate_keypairs(1).remove(0);
+    let res = holder
+        .call(keypom_contract.id(), "nft_transfer_call")
+        .args_json(json!({
+            "receiver_id": receiver_contract.id(),
+            "token_id": "nft_drop:1",
+            "memo": reverted_key.public_key(),
+            "msg": "missing_drop"
+        }))
+        .deposit(NearToken::from_yoctonear(1))
+        .gas(NearGas::from_tgas(100))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+    assert_eq!(res.json::<bool>()?, false);
+    assert_eq!(
+        get_token("nft_drop:1").await?["owner_id"],
+        json!(holder.id())
+    );
+    let key_info = keypom_contract
+        .view("get_key_information")
+        .args_json(json!({ "key": keys[1].public_key() }))
+        .await?
+        .json::<serde_json::Value>()?;
+    assert_eq!(key_info["token_id"], "nft_drop:1");
+
+    println!("      Passed ✅ test_nft_transfer_call");
+    Ok(())
+}
@@ -0,0 +1,179 @@
+use crate::*;
+
+/// Pay it forward keys should be paid for when the drop is created, only be minted once the claim goes through
+/// (including its claim gate) and whatever is left of the reserve should be refunded when the drop is deleted
+pub async fn test_pay_it_forward(worker: &Worker<Sandbox>, owner: &Account) -> anyhow::Result<()> {
+    let keypom_contract = deploy_keypom(worker, owner).await?;
+    let funder = create_account(owner, "pif-funder", NearToken::from_near(30)).await?;
+    let claimer = create_account(owner, "pif-claimer", NearToken::from_near(5)).await?;
+
+    let keys = generate_keypairs(2);
+    let res = funder
+        .call(keypom_contract.id(), "create_drop_batch")
+        .args_json(json!({
+            "drop_ids": ["pif_drop"],
+            "key_datas": [keys.iter().map(|k| json!({ "public_key": k.public_key() })).collect::<Vec<_>>()],
+            "asset_datas": [[{
+                "uses": 1,
+                "assets": [{ "yoctonear": NearToken::from_near(1).as_yoctonear().to_string() }],
+                "config": { "pay_it_forward": { "max_keys_per_claim": 1, "total_key_budget": 1 } }
+            }]],
+            "drop_configs": [null]
+        }))
+        .deposit(NearToken::from_near(10))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+    let results = res.json::<serde_json::Value>()?;
+    let reserved: u128 = results[0]["costs"]["pay_it_forward_cost"]
+        .as_str()
+        .unwrap()
+        .parse()?;
+    assert!(reserved > NearToken::from_near(1).as_yoctonear());
+
+    // The root account can't create accounts so the claim fails and nothing is minted
+    let forward_key = generate_keypairs(1).remove(0);
+    let new_account_id: AccountId = format!("pif.{}", owner.id()).parse()?;
+    let new_key = generate_keypairs(1).remove(0);
+    let res = claim_with_key(
+        &keypom_contract,
+        &keys[0],
+        "create_account_and_claim",
+        json!({
+            "new_account_id": new_account_id,
+            "new_public_key": new_key.public_key(),
+            "pay_it_forward_keys": [forward_key.public_key()]
+        }),
+        NearGas::from_tgas(0),
+    )
+    .await?;
+    assert!(res.is_success());
+    assert!(worker.view_account(&new_account_id).await.is_err());
+    assert!(keypom_contract
+        .view("get_key_information")
+        .args_json(json!({ "key": forward_key.public_key() }))
+        .await
+        .is_err());
+
+    // The failed claim gave the key back to the budget so a successful claim can still mint it
+    let res = claim_with_key(
+        &keypom_contract,
+        &keys[1],
+        "claim",
+        json!({ "account_id": claimer.id(), "pay_it_forward_keys": [forward_key.public_key()] }),
+        NearGas::from_tgas(0),
+    )
+    .await?;
+    assert!(res.is_success());
+    let key_info = keypom_contract
+        .view("get_key_information")
+        .args_json(json!({ "key": forward_key.public_key() }))
+        .await?
+        .json::<serde_json::Value>()?;
+    assert_eq!(key_info["owner_id"], json!(claimer.id()));
+
+    // The budget is now exhausted
+    let another_key = generate_keypairs(1).remove(0);
+    let res = claim_with_key(
+        &keypom_contract,
+        &forward_key,
+        "claim",
+        json!({ "account_id": claimer.id(), "pay_it_forward_keys": [another_key.public_key()] }),
+        NearGas::from_tgas(0),
+    )
+    .await?;
+    assert_failed_with(res, "Pay it forward key budget exhausted");
+
+    // Deleting a drop that never paid anything forward refunds the whole reserve
+    let res = funder
+        .call(keypom_contract.id(), "add_to_balance")
+        .deposit(NearToken::from_near(10))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+    let get_balance = || async {
+        anyhow::Ok(
+            keypom_contract
+                .view("get_user_balance")
+                .args_json(json!({ "account_id": funder.id() }))
+                .await?
+                .json::<U128>()?
+                .0,
+        )
+    };
+    let initial_balance = get_balance().await?;
+
+    let refund_key = generate_keypairs(1).remove(0);
+    let res = funder
+        .call(keypom_contract.id(), "create_drop")
+        .args_json(json!({
+            "drop_id": "pif_refund_drop",
+            "key_data": [{ "public_key": refund_key.public_key() }],
+            "asset_data": [{
+                "uses": 1,
+                "assets": [{ "yoctonear": NearToken::from_near(1).as_yoctonear().to_string() }],
+                "config": { "pay_it_forward": { "max_keys_per_claim": 1, "total_key_budget": 2 } }
+            }]
+        }))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+    assert!(get_balance().await? < initial_balance - 3 * NearToken::from_near(1).as_yoctonear());
+
+    let res = funder
+        .call(keypom_contract.id(), "delete_keys")
+        .args_json(json!({ "drop_id": "pif_refund_drop" }))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+    assert!(get_balance().await? + NearToken::from_millinear(10).as_yoctonear() >= initial_balance);
+
+    // Keys paid forward by a gated use are only minted if the claimer passes the gate. A second Keypom
+    // Instance acts as the NFT contract that the gate checks
+    let gate_contract = deploy_keypom(worker, owner).await?;
+    let gated_key = generate_keypairs(1).remove(0);
+    let res = funder
+        .call(keypom_contract.id(), "create_drop")
+        .args_json(json!({
+            "drop_id": "pif_gated_drop",
+            "key_data": [{ "public_key": gated_key.public_key() }],
+            "asset_data": [{
+                "uses": 1,
+                "assets": [null],
+                "config": {
+                    "claim_gate": { "nft_holder": { "contract_id": gate_contract.id() } },
+                    "pay_it_forward": { "max_keys_per_claim": 1, "total_key_budget": 1 }
+                }
+            }]
+        }))
+        .deposit(NearToken::from_near(5))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+
+    let forward_key = generate_keypairs(1).remove(0);
+    let res = claim_with_key(
+        &keypom_contract,
+        &gated_key,
+        "claim",
+        json!({ "account_id": claimer.id(), "pay_it_forward_keys": [forward_key.public_key()] }),
+        NearGas::from_tgas(0),
+    )
+    .await?;
+    assert!(res.is_success());
+    assert_eq!(res.json::<bool>()?, false);
+    assert!(keypom_contract
+        .view("get_key_information")
+        .args_json(json!({ "key": forward_key.public_key() }))
+        .await
+        .is_err());
+    let key_info = keypom_contract
+        .view("get_key_information")
+        .args_json(json!({ "key": gated_key.public_key() }))
+        .await?
+        .json::<serde_json::Value>()?;
+    assert_eq!(key_info["uses_remaining"], 1);
+
+    println!("      Passed ✅ test_pay_it_forward");
+    Ok(())
+}
@@ -0,0 +1,123 @@
+use crate::*;
+
+/// Number of nanoseconds in a day
+const NANOS_PER_DAY: u64 = 86_400 * 1_000_000_000;
+
+/// Keys on a weekly schedule should only be claimable once per period (when missed periods are forfeited)
+/// And report when they can next be claimed
+pub async fn test_recurring_schedules(
+    worker: &Worker<Sandbox>,
+    owner: &Account,
+) -> anyhow::Result<()> {
+    let keypom_contract = deploy_keypom(worker, owner).await?;
+    let funder = create_account(owner, "schedule-funder", NearToken::from_near(20)).await?;
+    let claimer = create_account(owner, "schedule-claimer", NearToken::from_near(5)).await?;
+
+    // Periods begin at midnight UTC on tomorrow's weekday (Monday is 0)
+    let now = worker.view_block().await?.timestamp();
+    let tomorrow = now / NANOS_PER_DAY + 1;
+    let first_period_start = tomorrow * NANOS_PER_DAY;
+    let day_of_week = (tomorrow + 3) % 7;
+
+    // Schedules need a start to be anchored to
+    let res = funder
+        .call(keypom_contract.id(), "create_drop")
+        .args_json(json!({
+            "drop_id": "invalid_drop",
+            "key_data": [],
+            "asset_data": [{
+                "uses": 4,
+                "assets": [null],
+                "config": { "time": { "schedule": { "period": { "weekly": { "day_of_week": day_of_week } }, "missed_periods": "forfeit" } } }
+            }]
+        }))
+        .deposit(NearToken::from_near(1))
+        .transact()
+        .await?;
+    assert_failed_with(
+        res,
+        "If you want to set a recurring schedule, you must also set a start timestamp",
+    );
+
+    let keys = generate_keypairs(1);
+    let res = funder
+        .call(keypom_contract.id(), "create_drop")
+        .args_json(json!({
+            "drop_id": "weekly_drop",
+            "key_data": [{ "public_key": keys[0].public_key() }],
+            "asset_data": [{
+                "uses": 4,
+                "assets": [null],
+                "config": {
+                    "time": {
+                        "start": now + 1_000_000_000,
+                        "schedule": {
+                            "period": { "weekly": { "day_of_week": day_of_week } },
+                            "claim_window": NANOS_PER_DAY,
+                            "missed_periods": "forfeit"
+                        }
+                    }
+                }
+            }]
+        }))
+        .deposit(NearToken::from_near(1))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+
+    let get_next_claimable = || async {
+        anyhow::Ok(
+            keypom_contract
+                .view("get_key_information")
+                .args_json(json!({ "key": keys[0].public_key() }))
+                .await?
+                .json::<serde_json::Value>()?["next_claimable_timestamp"]
+                .as_u64()
+                .unwrap(),
+        )
+    };
+
+    // The first period hasn't begun yet
+    assert_eq!(get_next_claimable().await?, first_period_start);
+    let res = claim_with_key(
+        &keypom_contract,
+        &keys[0],
+        "claim",
+        json!({ "account_id": claimer.id() }),
+        NearGas::from_tgas(0),
+    )
+    .await?;
+    assert_failed_with(res, "isn't claimable until");
+
+    // Move into the first period's window
+    while worker.view_block().await?.timestamp() < first_period_start {
+        worker.fast_forward(10_000).await?;
+    }
+    let res = claim_with_key(
+        &keypom_contract,
+        &keys[0],
+        "claim",
+        json!({ "account_id": claimer.id() }),
+        NearGas::from_tgas(0),
+    )
+    .await?;
+    assert!(res.is_success());
+
+    // The key can't be claimed again until next week
+    let res = claim_with_key(
+        &keypom_contract,
+        &keys[0],
+        "claim",
+        json!({ "account_id": claimer.id() }),
+        NearGas::from_tgas(0),
+    )
+    .await?;
+    assert_failed_with(res, "has already been claimed this period");
+    assert_eq!(
+        get_next_claimable().await?,
+        first_period_start + 7 * NANOS_PER_DAY
+    );
+
+    println!("      Passed ✅ test_recurring_schedules");
+    Ok(())
+}
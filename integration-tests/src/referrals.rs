@@ -0,0 +1,100 @@
+use crate::*;
+
+/// Referrers should earn the drop's reward for every successful claim up to their limit.
+/// Rewards that can't be paid out are given back to the funder's balance
+pub async fn test_referrals(worker: &Worker<Sandbox>, owner: &Account) -> anyhow::Result<()> {
+    let keypom_contract = deploy_keypom(worker, owner).await?;
+    let funder = create_account(owner, "referral-funder", NearToken::from_near(20)).await?;
+    let claimer = create_account(owner, "referral-claimer", NearToken::from_near(5)).await?;
+    let referrer = create_account(owner, "referral-referrer", NearToken::from_near(5)).await?;
+
+    let keys = generate_keypairs(3);
+    let res = funder
+        .call(keypom_contract.id(), "create_drop")
+        .args_json(json!({
+            "drop_id": "referral_drop",
+            "key_data": keys.iter().map(|k| json!({ "public_key": k.public_key() })).collect::<Vec<_>>(),
+            "asset_data": [{
+                "uses": 1,
+                "assets": [{ "yoctonear": NearToken::from_near(1).as_yoctonear().to_string() }],
+                "config": {
+                    "referral": {
+                        "reward": { "yoctonear": NearToken::from_millinear(100).as_yoctonear().to_string() },
+                        "max_rewards_per_referrer": 1
+                    }
+                }
+            }]
+        }))
+        .deposit(NearToken::from_near(6))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+
+    // Claimers can't refer themselves
+    let res = claim_with_key(
+        &keypom_contract,
+        &keys[0],
+        "claim",
+        json!({ "account_id": claimer.id(), "referrer_id": claimer.id() }),
+        NearGas::from_tgas(0),
+    )
+    .await?;
+    assert_failed_with(res, "Cannot refer yourself when claiming");
+
+    // The first referred claim pays the referrer
+    let referrer_balance = referrer.view_account().await?.balance;
+    let res = claim_with_key(
+        &keypom_contract,
+        &keys[0],
+        "claim",
+        json!({ "account_id": claimer.id(), "referrer_id": referrer.id() }),
+        NearGas::from_tgas(0),
+    )
+    .await?;
+    assert!(res.is_success());
+    assert_eq!(
+        referrer.view_account().await?.balance,
+        referrer_balance.saturating_add(NearToken::from_millinear(100))
+    );
+
+    let leaderboard = keypom_contract
+        .view("get_referral_leaderboard")
+        .args_json(json!({ "drop_id": "referral_drop" }))
+        .await?
+        .json::<serde_json::Value>()?;
+    assert_eq!(
+        leaderboard,
+        json!([{ "referrer_id": referrer.id(), "num_rewards": 1 }])
+    );
+
+    // Once the referrer hits their limit, the reward goes back to the funder instead
+    let funder_balance = keypom_contract
+        .view("get_user_balance")
+        .args_json(json!({ "account_id": funder.id() }))
+        .await?
+        .json::<U128>()?;
+    let res = claim_with_key(
+        &keypom_contract,
+        &keys[1],
+        "claim",
+        json!({ "account_id": claimer.id(), "referrer_id": referrer.id() }),
+        NearGas::from_tgas(0),
+    )
+    .await?;
+    assert!(res.is_success());
+    let new_funder_balance = keypom_contract
+        .view("get_user_balance")
+        .args_json(json!({ "account_id": funder.id() }))
+        .await?
+        .json::<U128>()?;
+    assert!(
+        new_funder_balance.0 >= funder_balance.0 + NearToken::from_millinear(100).as_yoctonear()
+    );
+    assert_eq!(
+        referrer.view_account().await?.balance,
+        referrer_balance.saturating_add(NearToken::from_millinear(100))
+    );
+
+    println!("      Passed ✅ test_referrals");
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use crate::*;
+
+/// Secondary sales of NFT keys should be capped at the drop's maximum resale price and pay the funder their
+/// Revenue share on top of the drop's royalties. Royalties that could never be paid out are rejected up front.
+pub async fn test_resale_payouts(worker: &Worker<Sandbox>, owner: &Account) -> anyhow::Result<()> {
+    let keypom_contract = deploy_keypom(worker, owner).await?;
+    let funder = create_account(owner, "resale-funder", NearToken::from_near(20)).await?;
+    let holder = create_account(owner, "resale-holder", NearToken::from_near(5)).await?;
+    let buyer = create_account(owner, "resale-buyer", NearToken::from_near(5)).await?;
+
+    // The royalties and funder share add up to more than 100%
+    let res = funder
+        .call(keypom_contract.id(), "create_drop")
+        .args_json(json!({
+            "drop_id": "invalid_drop",
+            "key_data": [],
+            "asset_data": [{ "uses": 1, "assets": [null] }],
+            "drop_config": {
+                "nft_keys_config": {
+                    "royalties": { owner.id().to_string(): 9000 },
+                    "funder_revenue_share": 2000
+                }
+            }
+        }))
+        .deposit(NearToken::from_near(1))
+        .transact()
+        .await?;
+    assert_failed_with(res, "Royalties cannot total more than 10000 basis points");
+
+    let keys = generate_keypairs(1);
+    let res = funder
+        .call(keypom_contract.id(), "create_drop")
+        .args_json(json!({
+            "drop_id": "ticket_drop",
+            "key_data": [{ "public_key": keys[0].public_key(), "key_owner": holder.id() }],
+            "asset_data": [{ "uses": 1, "assets": [null] }],
+            "drop_config": {
+                "nft_keys_config": {
+                    "royalties": { owner.id().to_string(): 500 },
+                    "funder_revenue_share": 1000,
+                    "max_resale_price": NearToken::from_near(10).as_yoctonear().to_string()
+                }
+            }
+        }))
+        .deposit(NearToken::from_near(1))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+
+    // Selling above the cap is rejected and the key stays with the holder
+    let res = holder
+        .call(keypom_contract.id(), "nft_transfer_payout")
+        .args_json(json!({
+            "receiver_id": buyer.id(),
+            "token_id": "ticket_drop:0",
+            "memo": generate_keypairs(1)[0].public_key(),
+            "balance": NearToken::from_near(20).as_yoctonear().to_string()
+        }))
+        .deposit(NearToken::from_yoctonear(1))
+        .transact()
+        .await?;
+    assert_failed_with(res, "exceeds the maximum of");
+
+    // At the cap, the funder and royalty receiver get their cut and the seller gets the rest
+    let res = holder
+        .call(keypom_contract.id(), "nft_transfer_payout")
+        .args_json(json!({
+            "receiver_id": buyer.id(),
+            "token_id": "ticket_drop:0",
+            "memo": generate_keypairs(1)[0].public_key(),
+            "balance": NearToken::from_near(10).as_yoctonear().to_string()
+        }))
+        .deposit(NearToken::from_yoctonear(1))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+    let payout = res.json::<serde_json::Value>()?["payout"].clone();
+    assert_eq!(
+        payout[funder.id().as_str()],
+        NearToken::from_near(1).as_yoctonear().to_string()
+    );
+    assert_eq!(
+        payout[owner.id().as_str()],
+        NearToken::from_millinear(500).as_yoctonear().to_string()
+    );
+    assert_eq!(
+        payout[holder.id().as_str()],
+        NearToken::from_millinear(8500).as_yoctonear().to_string()
+    );
+
+    let token = keypom_contract
+        .view("nft_token")
+        .args_json(json!({ "token_id": "ticket_drop:0" }))
+        .await?
+        .json::<serde_json::Value>()?;
+    assert_eq!(token["owner_id"], json!(buyer.id()));
+
+    println!("      Passed ✅ test_resale_payouts");
+    Ok(())
+}
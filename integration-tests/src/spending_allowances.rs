@@ -0,0 +1,92 @@
+use crate::*;
+
+/// Accounts with a spending allowance should be able to create drops owned by the funder that granted it,
+/// Paid for out of the funder's balance, until they hit the allowance's limit
+pub async fn test_spending_allowances(
+    worker: &Worker<Sandbox>,
+    owner: &Account,
+) -> anyhow::Result<()> {
+    let keypom_contract = deploy_keypom(worker, owner).await?;
+    let treasury = create_account(owner, "allowance-treasury", NearToken::from_near(30)).await?;
+    let ops = create_account(owner, "allowance-ops", NearToken::from_near(5)).await?;
+    let outsider = create_account(owner, "allowance-outsider", NearToken::from_near(5)).await?;
+
+    let res = treasury
+        .call(keypom_contract.id(), "add_to_balance")
+        .deposit(NearToken::from_near(10))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+    let res = treasury
+        .call(keypom_contract.id(), "grant_spending_allowance")
+        .args_json(json!({
+            "spender_id": ops.id(),
+            "total_limit": NearToken::from_near(1).as_yoctonear().to_string()
+        }))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+
+    let create_drop = |caller: &Account, drop_id: &str, yoctonear: u128| {
+        caller
+            .call(keypom_contract.id(), "create_drop")
+            .args_json(json!({
+                "drop_id": drop_id,
+                "key_data": [{ "public_key": generate_keypairs(1)[0].public_key() }],
+                "asset_data": [{ "uses": 1, "assets": [{ "yoctonear": yoctonear.to_string() }] }],
+                "funder_id": treasury.id()
+            }))
+            .transact()
+    };
+
+    // Accounts without an allowance can't spend the treasury's balance
+    let res = create_drop(
+        &outsider,
+        "outsider_drop",
+        NearToken::from_millinear(100).as_yoctonear(),
+    )
+    .await?;
+    assert_failed_with(res, "No spending allowance found for spender");
+
+    // The drop belongs to the treasury and what it cost counts towards the allowance
+    let res = create_drop(
+        &ops,
+        "ops_drop",
+        NearToken::from_millinear(100).as_yoctonear(),
+    )
+    .await?;
+    assert!(res.is_success());
+    assert!(res.logs().iter().any(|log| log.contains("allowance_spend")));
+    let drop = keypom_contract
+        .view("get_drop_information")
+        .args_json(json!({ "drop_id": "ops_drop" }))
+        .await?
+        .json::<serde_json::Value>()?;
+    assert_eq!(drop["funder_id"], json!(treasury.id()));
+
+    let allowance = keypom_contract
+        .view("get_spending_allowance")
+        .args_json(json!({ "funder_id": treasury.id(), "spender_id": ops.id() }))
+        .await?
+        .json::<serde_json::Value>()?;
+    let total_spent: u128 = allowance["total_spent"].as_str().unwrap().parse()?;
+    assert!(total_spent > NearToken::from_millinear(100).as_yoctonear());
+
+    // Going over the limit is rejected and nothing is taken from the treasury
+    let balance_before = keypom_contract
+        .view("get_user_balance")
+        .args_json(json!({ "account_id": treasury.id() }))
+        .await?
+        .json::<U128>()?;
+    let res = create_drop(&ops, "big_drop", NearToken::from_near(2).as_yoctonear()).await?;
+    assert_failed_with(res, "Spending allowance exceeded");
+    let balance_after = keypom_contract
+        .view("get_user_balance")
+        .args_json(json!({ "account_id": treasury.id() }))
+        .await?
+        .json::<U128>()?;
+    assert_eq!(balance_before, balance_after);
+
+    println!("      Passed ✅ test_spending_allowances");
+    Ok(())
+}
@@ -3,13 +3,42 @@ use std::str::FromStr;
 use helpers::*;
 use near_crypto::{KeyType, SecretKey, Signer};
 use near_gas::NearGas;
-use near_sdk::{json_types::Base64VecU8, NearToken};
+use near_sdk::{
+    json_types::{Base64VecU8, U128},
+    NearToken,
+};
 use near_units::parse_near;
-use near_workspaces::{AccessKey, Account, Contract};
+use near_workspaces::network::Sandbox;
+use near_workspaces::{AccessKey, Account, AccountId, Contract, Worker};
 use serde_json::json;
 
+mod account_naming;
+mod approvals;
+mod batch_drops;
+mod claim_gates;
+mod cost_quotes;
+mod crowdfund_collection;
+mod drop_funding;
+mod drop_ids;
+mod drop_roles;
+mod drop_templates;
+mod drop_transfers;
+mod ft_deposits;
 mod helpers;
+mod implicit_claims;
+mod key_metadata;
+mod key_splitting;
+mod key_time_configs;
+mod key_updates;
+mod migration;
 mod models;
+mod nft_transfer_call;
+mod pay_it_forward;
+mod recurring_schedules;
+mod referrals;
+mod resale_payouts;
+mod spending_allowances;
+mod transfer_restrictions;
 
 const KEYPOM_WASM_PATH: &str = "./out/keypom.wasm";
 
@@ -55,6 +84,31 @@ async fn main() -> anyhow::Result<()> {
 
     // begin tests
     claims_testing(&alice, keypom_contract).await?;
+    implicit_claims::test_implicit_claims(&worker, &owner).await?;
+    account_naming::test_account_naming_rules(&worker, &owner).await?;
+    claim_gates::test_claim_gates(&worker, &owner).await?;
+    referrals::test_referrals(&worker, &owner).await?;
+    pay_it_forward::test_pay_it_forward(&worker, &owner).await?;
+    nft_transfer_call::test_nft_transfer_call(&worker, &owner).await?;
+    key_metadata::test_key_token_metadata(&worker, &owner).await?;
+    migration::test_migration(&worker, &owner).await?;
+    transfer_restrictions::test_transfer_restrictions(&worker, &owner).await?;
+    resale_payouts::test_resale_payouts(&worker, &owner).await?;
+    approvals::test_approvals(&worker, &owner).await?;
+    key_splitting::test_key_splitting(&worker, &owner).await?;
+    key_updates::test_key_updates(&worker, &owner).await?;
+    key_time_configs::test_key_time_configs(&worker, &owner).await?;
+    recurring_schedules::test_recurring_schedules(&worker, &owner).await?;
+    spending_allowances::test_spending_allowances(&worker, &owner).await?;
+    drop_roles::test_drop_roles(&worker, &owner).await?;
+    drop_transfers::test_drop_transfers(&worker, &owner).await?;
+    drop_templates::test_drop_templates(&worker, &owner).await?;
+    drop_ids::test_auto_drop_ids(&worker, &owner).await?;
+    batch_drops::test_batch_drops(&worker, &owner).await?;
+    cost_quotes::test_cost_quotes(&worker, &owner).await?;
+    drop_funding::test_drop_funding(&worker, &owner).await?;
+    ft_deposits::test_ft_deposits(&worker, &owner).await?;
+    crowdfund_collection::test_crowdfund_collection(&worker, &owner).await?;
     Ok(())
 }
 
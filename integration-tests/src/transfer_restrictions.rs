@@ -0,0 +1,120 @@
+use crate::*;
+
+/// Soulbound keys should never move and keys that are only transferable before their first use should
+/// Stay transferable until they are claimed, even if they were split off another key
+pub async fn test_transfer_restrictions(
+    worker: &Worker<Sandbox>,
+    owner: &Account,
+) -> anyhow::Result<()> {
+    let keypom_contract = deploy_keypom(worker, owner).await?;
+    let funder = create_account(owner, "restrict-funder", NearToken::from_near(20)).await?;
+    let holder = create_account(owner, "restrict-holder", NearToken::from_near(10)).await?;
+    let buyer = create_account(owner, "restrict-buyer", NearToken::from_near(5)).await?;
+
+    let soulbound_keys = generate_keypairs(1);
+    let res = funder
+        .call(keypom_contract.id(), "create_drop")
+        .args_json(json!({
+            "drop_id": "soulbound_drop",
+            "key_data": [{ "public_key": soulbound_keys[0].public_key(), "key_owner": holder.id() }],
+            "asset_data": [{ "uses": 1, "assets": [null] }],
+            "drop_config": { "nft_keys_config": { "transfer_restrictions": { "non_transferable": true } } }
+        }))
+        .deposit(NearToken::from_near(1))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+
+    // Soulbound keys can't be transferred or approved for transfer
+    let res = holder
+        .call(keypom_contract.id(), "nft_transfer")
+        .args_json(json!({
+            "token_id": "soulbound_drop:0",
+            "receiver_id": buyer.id(),
+            "memo": generate_keypairs(1)[0].public_key()
+        }))
+        .deposit(NearToken::from_yoctonear(1))
+        .transact()
+        .await?;
+    assert_failed_with(res, "Keys in this drop cannot be transferred");
+    let res = holder
+        .call(keypom_contract.id(), "nft_approve")
+        .args_json(json!({ "token_id": "soulbound_drop:0", "account_id": buyer.id() }))
+        .deposit(NearToken::from_millinear(10))
+        .transact()
+        .await?;
+    assert_failed_with(res, "Keys in this drop cannot be transferred");
+
+    let keys = generate_keypairs(2);
+    let res = funder
+        .call(keypom_contract.id(), "create_drop")
+        .args_json(json!({
+            "drop_id": "ticket_drop",
+            "key_data": keys.iter().map(|k| json!({ "public_key": k.public_key(), "key_owner": holder.id() })).collect::<Vec<_>>(),
+            "asset_data": [{ "uses": 3, "assets": [null] }],
+            "drop_config": { "nft_keys_config": { "transfer_restrictions": { "only_before_first_use": true } } }
+        }))
+        .deposit(NearToken::from_near(2))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+
+    // A key split off an unused key has fewer uses than the drop allows but still hasn't been used
+    let split_key = generate_keypairs(1).remove(0);
+    let res = holder
+        .call(keypom_contract.id(), "split_key")
+        .args_json(json!({
+            "token_id": "ticket_drop:0",
+            "new_public_keys": [split_key.public_key()],
+            "uses_each": 1
+        }))
+        .deposit(NearToken::from_millinear(100))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+    let res = holder
+        .call(keypom_contract.id(), "nft_transfer")
+        .args_json(json!({
+            "token_id": "ticket_drop:2",
+            "receiver_id": buyer.id(),
+            "memo": generate_keypairs(1)[0].public_key()
+        }))
+        .deposit(NearToken::from_yoctonear(1))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+    let token = keypom_contract
+        .view("nft_token")
+        .args_json(json!({ "token_id": "ticket_drop:2" }))
+        .await?
+        .json::<serde_json::Value>()?;
+    assert_eq!(token["owner_id"], json!(buyer.id()));
+
+    // Once a use has been claimed the key can't be transferred anymore
+    let res = claim_with_key(
+        &keypom_contract,
+        &keys[1],
+        "claim",
+        json!({ "account_id": holder.id() }),
+        NearGas::from_tgas(0),
+    )
+    .await?;
+    assert!(res.is_success());
+    let res = holder
+        .call(keypom_contract.id(), "nft_transfer")
+        .args_json(json!({
+            "token_id": "ticket_drop:1",
+            "receiver_id": buyer.id(),
+            "memo": generate_keypairs(1)[0].public_key()
+        }))
+        .deposit(NearToken::from_yoctonear(1))
+        .transact()
+        .await?;
+    assert_failed_with(
+        res,
+        "Keys in this drop cannot be transferred once they have been used",
+    );
+
+    println!("      Passed ✅ test_transfer_restrictions");
+    Ok(())
+}